@@ -0,0 +1,36 @@
+extern crate env_logger;
+extern crate futures;
+extern crate jira_rs;
+extern crate tokio;
+
+use futures::{Future, Stream};
+use jira_rs::{AsyncJira, Credentials};
+use std::env;
+
+fn main() {
+    drop(env_logger::init());
+    if let (Ok(host), Ok(user), Ok(pass)) = (
+        env::var("JIRA_HOST"),
+        env::var("JIRA_USER"),
+        env::var("JIRA_PASS"),
+    ) {
+        let query = env::args().nth(1).unwrap_or("assignee=doug".to_owned());
+
+        let jira = AsyncJira::new(host, Credentials::Basic(user, pass)).unwrap();
+
+        let work = jira
+            .issues()
+            .search_stream(&query, &Default::default())
+            .for_each(|issue| {
+                println!(
+                    "{} {}",
+                    issue.key,
+                    issue.summary().unwrap_or("???".to_owned())
+                );
+                Ok(())
+            })
+            .map_err(|err| panic!("{:#?}", err));
+
+        tokio::run(work);
+    }
+}
@@ -1,7 +1,7 @@
 extern crate env_logger;
-extern crate goji;
+extern crate jira_rs;
 
-use goji::{Credentials, Jira};
+use jira_rs::{Credentials, Jira};
 use std::env;
 
 fn main() {
@@ -15,9 +15,9 @@ fn main() {
 
         let jira = Jira::new(host, Credentials::Basic(user, pass)).unwrap();
 
-        match jira.search().iter(query, &Default::default()) {
-            Ok(results) => for issue in results {
-                println!(
+        for issue in jira.search().iter(query, &Default::default()) {
+            match issue {
+                Ok(issue) => println!(
                     "{} {} ({}): reporter {} assignee {}",
                     issue.key,
                     issue.summary().unwrap_or("???".to_owned()),
@@ -33,9 +33,9 @@ fn main() {
                         .assignee()
                         .map(|value| value.display_name,)
                         .unwrap_or("???".to_owned(),)
-                );
-            },
-            Err(err) => panic!("{:#?}", err),
+                ),
+                Err(err) => panic!("{:#?}", err),
+            }
         }
     }
 }
@@ -1,7 +1,7 @@
 extern crate env_logger;
-extern crate goji;
+extern crate jira_rs;
 
-use goji::{Credentials, Jira, TransitionTriggerOptions};
+use jira_rs::{Credentials, Jira, TransitionTriggerOptions};
 use std::env;
 
 fn main() {
@@ -16,7 +16,7 @@ fn main() {
 
         println!("{:#?}", jira.issues().get(key.clone()));
         let transitions = jira.transitions(key);
-        for option in transitions.list() {
+        for option in transitions.list().unwrap_or_default() {
             println!("{:#?}", option);
         }
         if let Ok(transition_id) = env::var("JIRA_TRANSITION_ID") {
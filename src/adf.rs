@@ -0,0 +1,80 @@
+//! Atlassian document format, the rich text representation used by version 3 of the rest api
+//! https://developer.atlassian.com/cloud/jira/platform/apis/document/structure/
+
+// Third party
+use serde_json::{json, Value};
+
+/// the root node of an atlassian document format document
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AdfDocument {
+    pub version: u32,
+    #[serde(rename = "type")]
+    pub node_type: String,
+    /// the document's block nodes, such as paragraphs and headings
+    pub content: Vec<Value>,
+}
+
+impl AdfDocument {
+    /// wraps plain text in a document holding a single paragraph
+    pub fn from_plain(text: &str) -> AdfDocument {
+        // adf forbids empty text nodes, so empty text becomes an empty paragraph
+        let paragraph = if text.is_empty() {
+            json!({ "type": "paragraph", "content": [] })
+        } else {
+            json!({
+                "type": "paragraph",
+                "content": [{ "type": "text", "text": text }]
+            })
+        };
+        AdfDocument {
+            version: 1,
+            node_type: "doc".to_owned(),
+            content: vec![paragraph],
+        }
+    }
+}
+
+/// the body of a comment. version 2 of the rest api uses plain
+/// text while version 3 uses atlassian document format
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum CommentBody {
+    Plain(String),
+    Document(AdfDocument),
+}
+
+impl CommentBody {
+    /// returns the text of a plain text body
+    pub fn as_plain(&self) -> Option<&str> {
+        match *self {
+            CommentBody::Plain(ref text) => Some(text),
+            CommentBody::Document(_) => None,
+        }
+    }
+
+    /// returns the document of an atlassian document format body
+    pub fn as_document(&self) -> Option<&AdfDocument> {
+        match *self {
+            CommentBody::Plain(_) => None,
+            CommentBody::Document(ref document) => Some(document),
+        }
+    }
+}
+
+impl From<String> for CommentBody {
+    fn from(text: String) -> CommentBody {
+        CommentBody::Plain(text)
+    }
+}
+
+impl<'a> From<&'a str> for CommentBody {
+    fn from(text: &'a str) -> CommentBody {
+        CommentBody::Plain(text.to_owned())
+    }
+}
+
+impl From<AdfDocument> for CommentBody {
+    fn from(document: AdfDocument) -> CommentBody {
+        CommentBody::Document(document)
+    }
+}
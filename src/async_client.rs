@@ -0,0 +1,298 @@
+//! Non-blocking interfaces built on reqwest's async client
+//!
+//! Available when the `async` feature is enabled
+
+// Third party
+use futures::future::Loop;
+use futures::{future, stream, Future, Stream};
+use reqwest::header::{ACCEPT, CONTENT_TYPE, USER_AGENT};
+use reqwest::r#async::{Client, Response};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json;
+use std::io;
+use std::time::Instant;
+use tokio_timer::Delay;
+use url::form_urlencoded;
+
+// Ours
+use retry::RetryPolicy;
+use {
+    content_type, respond, rest_url, ApiVersion, CreateIssue, CreateResponse, Credentials, Error,
+    Issue, JiraBuilder, Paginated, SearchOptions,
+};
+
+/// upper bound on the pages `AsyncIssues::search_all_parallel` requests at once, keeping
+/// large scans within jira's rate limits
+const MAX_CONCURRENT_PAGES: usize = 8;
+
+/// a future resolving to a value or a jira error
+pub type JiraFuture<T> = Box<dyn Future<Item = T, Error = Error> + Send>;
+
+/// a stream of values, failing with a jira error
+pub type JiraStream<T> = Box<dyn Stream<Item = T, Error = Error> + Send>;
+
+/// Entrypoint into the non-blocking client interface
+#[derive(Clone, Debug)]
+pub struct AsyncJira {
+    host: String,
+    credentials: Credentials,
+    client: Client,
+    api_version: ApiVersion,
+    user_agent: String,
+    retry: RetryPolicy,
+}
+
+impl JiraBuilder {
+    /// creates a non-blocking jira client with these settings. a blocking reqwest client
+    /// supplied with `client` can't send async requests, so is not used, see
+    /// `AsyncJira::from_client`
+    pub fn build_async(&self) -> ::Result<AsyncJira> {
+        let mut client = Client::builder().gzip(self.gzip);
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            client = client.connect_timeout(timeout);
+        }
+        Ok(self.build_async_with(client.build()?))
+    }
+
+    fn build_async_with(&self, client: Client) -> AsyncJira {
+        AsyncJira {
+            host: self.host.trim_end_matches('/').to_owned(),
+            credentials: self.credentials.clone(),
+            client,
+            api_version: self.api_version,
+            user_agent: self.user_agent.clone(),
+            retry: self.retry.clone(),
+        }
+    }
+}
+
+impl AsyncJira {
+    /// creates a new instance of a non-blocking jira client
+    pub fn new<H>(host: H, credentials: Credentials) -> ::Result<AsyncJira>
+    where
+        H: Into<String>,
+    {
+        JiraBuilder::new(host, credentials).build_async()
+    }
+
+    /// creates a new instance of a non-blocking jira client using a specified reqwest client
+    pub fn from_client<H>(host: H, credentials: Credentials, client: Client) -> ::Result<AsyncJira>
+    where
+        H: Into<String>,
+    {
+        Ok(JiraBuilder::new(host, credentials).build_async_with(client))
+    }
+
+    // return issues interface
+    pub fn issues(&self) -> AsyncIssues {
+        AsyncIssues::new(self)
+    }
+
+    pub fn post<D, S>(&self, api_name: &str, endpoint: &str, body: S) -> JiraFuture<D>
+    where
+        D: DeserializeOwned + Send + 'static,
+        S: Serialize,
+    {
+        match serde_json::to_string::<S>(&body) {
+            Ok(data) => {
+                debug!("Json request: {}", data);
+                self.request::<D>(Method::POST, api_name, endpoint, Some(data.into_bytes()))
+            }
+            Err(e) => Box::new(future::err(e.into())),
+        }
+    }
+
+    pub fn get<D>(&self, api_name: &str, endpoint: &str) -> JiraFuture<D>
+    where
+        D: DeserializeOwned + Send + 'static,
+    {
+        self.request::<D>(Method::GET, api_name, endpoint, None)
+    }
+
+    /// sends a request, retrying it as configured with `JiraBuilder::max_retries`. waiting
+    /// between retries requires a tokio timer, as provided by a tokio runtime
+    pub fn request<D>(
+        &self,
+        method: Method,
+        api_name: &str,
+        endpoint: &str,
+        body: Option<Vec<u8>>,
+    ) -> JiraFuture<D>
+    where
+        D: DeserializeOwned + Send + 'static,
+    {
+        let url = rest_url(&self.host, self.api_version, api_name, endpoint);
+        let jira = self.clone();
+        let attempts = future::loop_fn(0, move |attempt| {
+            let retry = jira.retry.clone();
+            let method = method.clone();
+            let url = url.clone();
+            jira.send(method.clone(), &url, body.clone()).and_then(
+                move |res| -> JiraFuture<Loop<Response, u32>> {
+                    if !retry.retries(&method, res.status(), attempt) {
+                        return Box::new(future::ok(Loop::Break(res)));
+                    }
+                    let delay = retry.delay(res.headers(), attempt);
+                    debug!("Retrying {} {} in {:?}", method, url, delay);
+                    Box::new(
+                        Delay::new(Instant::now() + delay)
+                            .map(move |_| Loop::Continue(attempt + 1))
+                            .map_err(|err| Error::IO(io::Error::other(err))),
+                    )
+                },
+            )
+        });
+
+        Box::new(
+            attempts
+                .and_then(|res| {
+                    let status = res.status();
+                    let content_type = content_type(res.headers());
+                    res.into_body()
+                        .concat2()
+                        .map(move |body| (status, content_type, body))
+                        .map_err(Error::from)
+                })
+                .and_then(|(status, content_type, body)| {
+                    respond(
+                        status,
+                        content_type.as_deref(),
+                        &String::from_utf8_lossy(&body),
+                    )
+                }),
+        )
+    }
+
+    /// sends a single authenticated attempt of a request
+    fn send(&self, method: Method, url: &str, body: Option<Vec<u8>>) -> JiraFuture<Response> {
+        let req = self
+            .client
+            .request(method, url)
+            .header(USER_AGENT, self.user_agent.as_str())
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json");
+        // resolved credentials are never `Auto`
+        let builder = match self.credentials.resolve(&self.host) {
+            Credentials::Basic(user, pass) | Credentials::Auto(user, pass) => {
+                req.basic_auth(user, Some(pass))
+            }
+            Credentials::Bearer(token) | Credentials::PersonalAccessToken(token) => {
+                req.bearer_auth(token)
+            }
+        };
+        let builder = match body {
+            Some(bod) => builder.body(bod),
+            _ => builder,
+        };
+
+        Box::new(builder.send().map_err(Error::from))
+    }
+}
+
+/// non-blocking issue options
+#[derive(Debug)]
+pub struct AsyncIssues {
+    jira: AsyncJira,
+}
+
+impl AsyncIssues {
+    pub fn new(jira: &AsyncJira) -> AsyncIssues {
+        AsyncIssues { jira: jira.clone() }
+    }
+
+    pub fn get<I>(&self, id: I) -> JiraFuture<Issue>
+    where
+        I: Into<String>,
+    {
+        self.jira.get("api", &format!("/issue/{}", id.into()))
+    }
+
+    pub fn create(&self, data: CreateIssue) -> JiraFuture<CreateResponse> {
+        self.jira.post("api", "/issue", data)
+    }
+
+    /// returns a single page of issues matching a jql query
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/search-search
+    pub fn search(&self, jql: &str, options: &SearchOptions) -> JiraFuture<Paginated<Issue>> {
+        let mut path = vec!["/search".to_owned()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("jql", jql)
+            .finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<Issue>>("api", path.join("?").as_ref())
+    }
+
+    /// returns a stream of every issue matching a jql query, fetching
+    /// consecutive pages of results as the stream is polled
+    pub fn search_stream(&self, jql: &str, options: &SearchOptions) -> JiraStream<Issue> {
+        let jira = self.jira.clone();
+        let jql = jql.to_owned();
+        // options for the next page to fetch, if any
+        let pages = stream::unfold(Some(options.clone()), move |options| {
+            let options = options?;
+            Some(jira.issues().search(&jql, &options).map(move |page| {
+                let next_start_at = page.start_at + page.values.len() as u64;
+                // an empty page means the server has nothing further to offer
+                let next = if next_start_at > page.start_at && next_start_at < page.total {
+                    Some(options.as_builder().start_at(next_start_at).build())
+                } else {
+                    None
+                };
+                (page.values, next)
+            }))
+        });
+        Box::new(pages.map(stream::iter_ok).flatten())
+    }
+
+    /// returns every issue matching a jql query, fetching the first page to learn the total
+    /// then up to `concurrency` of the remaining pages at once, capped at 8. issues are
+    /// returned in the order jira ranks them
+    pub fn search_all_parallel(
+        &self,
+        jql: &str,
+        options: &SearchOptions,
+        concurrency: usize,
+    ) -> JiraFuture<Vec<Issue>> {
+        let jira = self.jira.clone();
+        let jql = jql.to_owned();
+        let options = options.clone();
+        let concurrency = concurrency.clamp(1, MAX_CONCURRENT_PAGES);
+        Box::new(self.search(&jql, &options).and_then(move |first| {
+            // the server may cap the page size below what was asked for
+            let page_size = first.max_results.max(first.values.len() as u64);
+            let start_ats = if page_size == 0 {
+                vec![]
+            } else {
+                (1..)
+                    .map(|page| first.start_at + page * page_size)
+                    .take_while(|start_at| *start_at < first.total)
+                    .collect()
+            };
+            stream::iter_ok::<_, Error>(start_ats)
+                .map(move |start_at| {
+                    jira.issues().search(
+                        &jql,
+                        &options
+                            .as_builder()
+                            .start_at(start_at)
+                            .max_results(page_size)
+                            .build(),
+                    )
+                })
+                .buffered(concurrency)
+                .fold(first.values, |mut issues, page| {
+                    issues.extend(page.values);
+                    Ok::<_, Error>(issues)
+                })
+        }))
+    }
+}
@@ -4,7 +4,8 @@
 use url::form_urlencoded;
 
 // Ours
-use {Jira, Result, SearchOptions};
+use pagination::Fetch;
+use {Issue, Jira, PageIter, Paginated, Result, SearchOptions, Sprint};
 
 #[derive(Debug)]
 pub struct Boards {
@@ -21,15 +22,67 @@ pub struct Board {
     pub type_name: String,
 }
 
+/// how a board is set up: the filter selecting its issues, its columns and how work is estimated
 #[derive(Deserialize, Debug)]
-pub struct BoardResults {
-    #[serde(rename = "maxResults")]
-    pub max_results: u64,
-    #[serde(rename = "startAt")]
-    pub start_at: u64,
-    #[serde(rename = "isLast")]
-    pub is_last: bool,
-    pub values: Vec<Board>,
+pub struct BoardConfiguration {
+    pub id: u64,
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub name: String,
+    pub filter: BoardFilter,
+    #[serde(rename = "columnConfig")]
+    pub column_config: ColumnConfig,
+    pub estimation: Option<Estimation>,
+}
+
+/// the saved filter which selects a board's issues
+#[derive(Deserialize, Debug)]
+pub struct BoardFilter {
+    pub id: String,
+    #[serde(rename = "self")]
+    pub self_link: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ColumnConfig {
+    pub columns: Vec<BoardColumn>,
+    /// what column limits count, e.g. `issueCount`
+    #[serde(rename = "constraintType")]
+    pub constraint_type: Option<String>,
+}
+
+/// a board column, and the statuses an issue may have to appear in it
+#[derive(Deserialize, Debug)]
+pub struct BoardColumn {
+    pub name: String,
+    #[serde(default)]
+    pub statuses: Vec<ColumnStatus>,
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ColumnStatus {
+    pub id: String,
+    #[serde(rename = "self")]
+    pub self_link: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Estimation {
+    /// `field` when estimating with a field, `none` otherwise
+    #[serde(rename = "type")]
+    pub estimation_type: String,
+    pub field: Option<EstimationField>,
+}
+
+/// the field estimates are recorded in, e.g. story points
+#[derive(Deserialize, Debug)]
+pub struct EstimationField {
+    #[serde(rename = "fieldId")]
+    pub field_id: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
 }
 
 impl Boards {
@@ -37,22 +90,23 @@ impl Boards {
         Boards { jira: jira.clone() }
     }
 
-    /// Get a single board
+    /// Get a single board by its numeric id
     ///
     /// See this [jira docs](https://docs.atlassian.com/jira-software/REST/7.0.4/#agile/1.0/board-getBoard)
     /// for more information
     pub fn get<I>(&self, id: I) -> Result<Board>
     where
-        I: Into<String>,
+        I: ToString,
     {
-        self.jira.get("agile", &format!("/board/{}", id.into()))
+        self.jira.get("agile", &format!("/board/{}", id.to_string()))
     }
 
-    /// Returns a single page of board results
+    /// Returns a single page of board results. Narrow the results to a project's boards
+    /// with the `project_key_or_id` search option, or to scrum or kanban boards with `type_name`
     ///
     /// See the [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-getAllBoards)
     /// for more information
-    pub fn list(&self, options: &SearchOptions) -> Result<BoardResults> {
+    pub fn list(&self, options: &SearchOptions) -> Result<Paginated<Board>> {
         let mut path = vec!["/board".to_owned()];
         let query_options = options.serialize().unwrap_or_default();
         let query = form_urlencoded::Serializer::new(query_options).finish();
@@ -60,61 +114,81 @@ impl Boards {
         path.push(query);
 
         self.jira
-            .get::<BoardResults>("agile", path.join("?").as_ref())
+            .get::<Paginated<Board>>("agile", path.join("?").as_ref())
     }
 
-    /// Returns a type which may be used to iterate over consecutive pages of results
+    /// Returns a single page of the sprints on a board
+    ///
+    /// See the [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board/{boardId}/sprint-getAllSprints)
+    /// for more information
+    pub fn sprints(&self, board_id: u64, options: &SearchOptions) -> Result<Paginated<Sprint>> {
+        let mut path = vec![format!("/board/{}/sprint", board_id)];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<Sprint>>("agile", path.join("?").as_ref())
+    }
+
+    /// returns a single page of the issues in a board's backlog
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-getIssuesForBacklog
+    pub fn backlog(&self, board_id: u64, options: &SearchOptions) -> Result<Paginated<Issue>> {
+        let mut path = vec![format!("/board/{}/backlog", board_id)];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<Issue>>("agile", path.join("?").as_ref())
+    }
+
+    /// returns a board's configuration
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-getConfiguration
+    pub fn configuration(&self, board_id: u64) -> Result<BoardConfiguration> {
+        self.jira
+            .get("agile", &format!("/board/{}/configuration", board_id))
+    }
+
+    /// Returns a type which may be used to iterate over consecutive pages of results.
+    /// No request is made until the iterator is first advanced
     ///
     /// See the [jira docs](https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-getAllBoards)
     /// for more information
-    pub fn iter<'a>(&self, options: &'a SearchOptions) -> Result<BoardsIter<'a>> {
-        BoardsIter::new(options, &self.jira)
+    pub fn iter<'a>(&self, options: &'a SearchOptions) -> BoardsIter<'a> {
+        let jira = self.jira.clone();
+        BoardsIter {
+            pages: PageIter::new(
+                options,
+                Box::new(move |options| jira.boards().list(options)),
+            ),
+        }
     }
 }
 
 /// Provides an iterator over multiple pages of search results
 #[derive(Debug)]
 pub struct BoardsIter<'a> {
-    jira: Jira,
-    results: BoardResults,
-    search_options: &'a SearchOptions,
+    pages: PageIter<Board, Fetch<'a, Board>>,
 }
 
 impl<'a> BoardsIter<'a> {
-    fn new(options: &'a SearchOptions, jira: &Jira) -> Result<Self> {
-        let results = jira.boards().list(options)?;
-        Ok(BoardsIter {
-            jira: jira.clone(),
-            results,
-            search_options: options,
-        })
+    /// the number of boards fetched so far, including any not yet iterated over
+    pub fn fetched_so_far(&self) -> u64 {
+        self.pages.fetched_so_far()
     }
 
-    fn more(&self) -> bool {
-        !self.results.is_last
+    /// whether the last page of boards has been fetched
+    pub fn is_last_page(&self) -> bool {
+        self.pages.is_last_page()
     }
 }
 
 impl<'a> Iterator for BoardsIter<'a> {
-    type Item = Board;
-    fn next(&mut self) -> Option<Board> {
-        self.results.values.pop().or_else(|| {
-            if self.more() {
-                match self.jira.boards().list(&self.search_options
-                    .as_builder()
-                    .max_results(self.results.max_results)
-                    .start_at(self.results.start_at + self.results.max_results)
-                    .build())
-                {
-                    Ok(new_results) => {
-                        self.results = new_results;
-                        self.results.values.pop()
-                    }
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        })
+    type Item = Result<Board>;
+    fn next(&mut self) -> Option<Result<Board>> {
+        self.pages.next()
     }
 }
@@ -30,6 +30,42 @@ impl SearchOptions {
     pub fn as_builder(&self) -> SearchOptionsBuilder {
         SearchOptionsBuilder::copy_from(self)
     }
+
+    /// the expand options requested
+    pub fn expand(&self) -> Vec<&str> {
+        self.params
+            .get("expand")
+            .map(|value| value.split(',').filter(|e| !e.is_empty()).collect())
+            .unwrap_or_default()
+    }
+
+    /// the page size requested, if any
+    pub fn max_results(&self) -> Option<u64> {
+        self.params
+            .get("maxResults")
+            .and_then(|value| value.parse().ok())
+    }
+}
+
+/// levels of jql validation applied by the search endpoint
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValidateQuery {
+    /// invalid jql fails the search with error messages
+    Strict,
+    /// invalid clauses are dropped from the search and reported as warnings
+    Warn,
+    /// no validation is performed
+    None,
+}
+
+impl ValidateQuery {
+    fn as_str(self) -> &'static str {
+        match self {
+            ValidateQuery::Strict => "strict",
+            ValidateQuery::Warn => "warn",
+            ValidateQuery::None => "none",
+        }
+    }
 }
 
 /// a builder interface for search option
@@ -66,11 +102,32 @@ impl SearchOptionsBuilder {
         self
     }
 
+    /// issue properties to include with each issue, by key. `*all` includes every property
+    pub fn properties<P>(&mut self, ps: Vec<P>) -> &mut SearchOptionsBuilder
+    where
+        P: Into<String>,
+    {
+        self.params.insert(
+            "properties",
+            ps.into_iter()
+                .map(|p| p.into())
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+        self
+    }
+
     pub fn validate(&mut self, v: bool) -> &mut SearchOptionsBuilder {
         self.params.insert("validateQuery", v.to_string());
         self
     }
 
+    /// how strictly jira validates a search's jql. `validate` is the older boolean form
+    pub fn query_validation(&mut self, v: ValidateQuery) -> &mut SearchOptionsBuilder {
+        self.params.insert("validateQuery", v.as_str().to_owned());
+        self
+    }
+
     pub fn max_results(&mut self, m: u64) -> &mut SearchOptionsBuilder {
         self.params.insert("maxResults", m.to_string());
         self
@@ -120,6 +177,35 @@ impl SearchOptionsBuilder {
         self
     }
 
+    pub fn project_keys<K>(&mut self, keys: Vec<K>) -> &mut SearchOptionsBuilder
+    where
+        K: Into<String>,
+    {
+        self.params.insert(
+            "projectKeys",
+            keys.into_iter()
+                .map(|k| k.into())
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+        self
+    }
+
+    pub fn issue_type_names<N>(&mut self, names: Vec<N>) -> &mut SearchOptionsBuilder
+    where
+        N: Into<String>,
+    {
+        self.params.insert(
+            "issuetypeNames",
+            names
+                .into_iter()
+                .map(|n| n.into())
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+        self
+    }
+
     pub fn validate_query(&mut self, v: bool) -> &mut SearchOptionsBuilder {
         self.params.insert("validateQuery", v.to_string());
         self
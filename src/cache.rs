@@ -0,0 +1,42 @@
+//! Memoization of responses from endpoints serving rarely changing reference data
+
+// Third party
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// successful response bodies keyed by request url, each kept for a fixed time
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, String)>>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(ttl: Duration) -> ResponseCache {
+        ResponseCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// the body cached for `url`, if it has not yet expired
+    pub(crate) fn get(&self, url: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(url) {
+            Some((stored, body)) if stored.elapsed() < self.ttl => Some(body.clone()),
+            Some(_) => {
+                entries.remove(url);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(crate) fn insert(&self, url: &str, body: String) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(url.to_owned(), (Instant::now(), body));
+    }
+}
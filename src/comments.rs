@@ -0,0 +1,121 @@
+//! Interfaces for accessing and managing issue comments
+
+// Third party
+use url::form_urlencoded;
+
+// Ours
+use {Comment, CommentBody, Jira, Paginated, Result, SearchOptions, Visibility};
+
+/// comments interface for a single issue
+#[derive(Debug)]
+pub struct Comments {
+    jira: Jira,
+    key: String,
+}
+
+/// payload for adding a comment to an issue
+#[derive(Serialize, Debug)]
+pub struct AddComment {
+    /// plain text for version 2 of the rest api, a document for version 3
+    pub body: CommentBody,
+    /// restricts the comment to members of a role or group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+    /// whether customers may see the comment. only understood by the service desk api,
+    /// see `ServiceDesk::add_comment`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+}
+
+impl AddComment {
+    /// creates a comment visible to anyone who can view the issue
+    pub fn new<B>(body: B) -> AddComment
+    where
+        B: Into<CommentBody>,
+    {
+        AddComment {
+            body: body.into(),
+            visibility: None,
+            public: None,
+        }
+    }
+}
+
+/// payload for editing an existing comment
+#[derive(Serialize, Debug)]
+pub struct UpdateComment {
+    pub body: CommentBody,
+    /// a new restriction for the comment. when `None` the current restriction is kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+impl UpdateComment {
+    /// replaces a comment's body, keeping its visibility
+    pub fn new<B>(body: B) -> UpdateComment
+    where
+        B: Into<CommentBody>,
+    {
+        UpdateComment {
+            body: body.into(),
+            visibility: None,
+        }
+    }
+}
+
+impl Comments {
+    pub fn new<K>(jira: &Jira, key: K) -> Comments
+    where
+        K: Into<String>,
+    {
+        Comments {
+            jira: jira.clone(),
+            key: key.into(),
+        }
+    }
+
+    /// returns a single page of comments
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getComments
+    pub fn list(&self, options: &SearchOptions) -> Result<Paginated<Comment>> {
+        let mut path = vec![format!("/issue/{}/comment", self.key)];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<Comment>>("api", path.join("?").as_ref())
+    }
+
+    /// adds a new comment to the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-addComment
+    pub fn add(&self, comment: AddComment) -> Result<Comment> {
+        self.jira
+            .post("api", &format!("/issue/{}/comment", self.key), comment)
+    }
+
+    /// edits an existing comment
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-updateComment
+    pub fn update<C>(&self, comment_id: C, comment: UpdateComment) -> Result<Comment>
+    where
+        C: Into<String>,
+    {
+        self.jira.put(
+            "api",
+            &format!("/issue/{}/comment/{}", self.key, comment_id.into()),
+            comment,
+        )
+    }
+
+    /// removes a comment from the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-deleteComment
+    pub fn delete<C>(&self, comment_id: C) -> Result<()>
+    where
+        C: Into<String>,
+    {
+        self.jira.delete(
+            "api",
+            &format!("/issue/{}/comment/{}", self.key, comment_id.into()),
+        )
+    }
+}
@@ -0,0 +1,58 @@
+//! Interfaces for managing project components
+
+// Ours
+use {Component, Jira, Result};
+
+/// component options
+#[derive(Debug)]
+pub struct Components {
+    jira: Jira,
+}
+
+/// a new project component
+#[derive(Serialize, Debug, Default)]
+pub struct CreateComponent {
+    pub name: String,
+    /// the key of the project the component belongs to
+    pub project: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// the username of the component's lead
+    #[serde(rename = "leadUserName", skip_serializing_if = "Option::is_none")]
+    pub lead_user_name: Option<String>,
+}
+
+impl CreateComponent {
+    pub fn new<N, P>(name: N, project: P) -> CreateComponent
+    where
+        N: Into<String>,
+        P: Into<String>,
+    {
+        CreateComponent {
+            name: name.into(),
+            project: project.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Components {
+    pub fn new(jira: &Jira) -> Components {
+        Components { jira: jira.clone() }
+    }
+
+    /// returns a single component
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/component-getComponent
+    pub fn get<I>(&self, id: I) -> Result<Component>
+    where
+        I: Into<String>,
+    {
+        self.jira.get("api", &format!("/component/{}", id.into()))
+    }
+
+    /// creates a component in a project
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/component-createComponent
+    pub fn create(&self, data: CreateComponent) -> Result<Component> {
+        self.jira.post("api", "/component", data)
+    }
+}
@@ -0,0 +1,69 @@
+//! Interfaces for accessing dashboards
+
+// Third party
+use url::form_urlencoded;
+
+// Ours
+use {Jira, Paginated, Project, Result, SearchOptions};
+
+/// dashboard options
+#[derive(Debug)]
+pub struct Dashboards {
+    jira: Jira,
+}
+
+/// a page of gadgets, shared with some set of users
+#[derive(Deserialize, Debug)]
+pub struct Dashboard {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "self")]
+    pub self_link: String,
+    /// a link to the dashboard in the jira ui
+    pub view: Option<String>,
+    #[serde(rename = "sharePermissions", default)]
+    pub share_permissions: Vec<SharePermission>,
+}
+
+/// who a dashboard is shared with
+#[derive(Deserialize, Debug)]
+pub struct SharePermission {
+    pub id: Option<u64>,
+    /// e.g. `global`, `project`, `group` or `loggedin`
+    #[serde(rename = "type")]
+    pub share_type: String,
+    /// the project shared with, for `project` shares
+    pub project: Option<Project>,
+    /// the group shared with, for `group` shares
+    pub group: Option<SharedGroup>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SharedGroup {
+    pub name: String,
+}
+
+impl Dashboards {
+    pub fn new(jira: &Jira) -> Dashboards {
+        Dashboards { jira: jira.clone() }
+    }
+
+    /// returns a single dashboard
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/dashboard-getDashboard
+    pub fn get(&self, id: &str) -> Result<Dashboard> {
+        self.jira.get("api", &format!("/dashboard/{}", id))
+    }
+
+    /// returns a page of the dashboards visible to the current user
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/dashboard-list
+    pub fn list(&self, options: &SearchOptions) -> Result<Paginated<Dashboard>> {
+        let mut path = vec!["/dashboard".to_owned()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<Dashboard>>("api", path.join("?").as_ref())
+    }
+}
@@ -0,0 +1,84 @@
+//! Parsing of jira timestamps into chrono types
+//!
+//! Available when the `chrono` feature is enabled
+
+// Third party
+use chrono::{DateTime, FixedOffset, TimeZone};
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer};
+use std::fmt::Display;
+
+/// the format of jira timestamps, e.g. `2015-04-11T15:22:00.000+1000`
+const FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f%z";
+
+/// the format of dates in jql queries, e.g. `2015/04/11 15:22`
+const JQL_FORMAT: &str = "%Y/%m/%d %H:%M";
+
+/// formats a timestamp for use in a jql query. jira reads jql dates in the searching
+/// user's time zone, so the timestamp is converted to `zone`, e.g. the one named by
+/// `myself().timezone`, before formatting. seconds are truncated
+pub fn jql<Tz, Z>(at: &DateTime<Tz>, zone: &Z) -> String
+where
+    Tz: TimeZone,
+    Z: TimeZone,
+    Z::Offset: Display,
+{
+    at.with_timezone(zone).format(JQL_FORMAT).to_string()
+}
+
+/// parses a jira timestamp, retaining its offset
+pub fn parse(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_str(value, FORMAT).ok()
+}
+
+/// deserializes a jira timestamp, for use with `#[serde(deserialize_with = "...")]`
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    parse(&value).ok_or_else(|| D::Error::custom(format!("invalid jira timestamp {}", value)))
+}
+
+/// deserializes an optional jira timestamp, for use with `#[serde(deserialize_with = "...")]`
+pub fn deserialize_option<'de, D>(deserializer: D) -> Result<Option<DateTime<FixedOffset>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(value) => parse(&value)
+            .map(Some)
+            .ok_or_else(|| D::Error::custom(format!("invalid jira timestamp {}", value))),
+        None => Ok(None),
+    }
+}
+
+/// deserializes a jira timestamp as jira sent it, failing when it does not parse. timestamp
+/// fields keep their `String` type with the `chrono` feature, so this only validates them
+pub fn deserialize_checked<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    checked(value)
+}
+
+/// deserializes an optional jira timestamp as jira sent it, failing when it does not parse
+pub fn deserialize_checked_option<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(checked)
+        .transpose()
+}
+
+fn checked<E>(value: String) -> Result<String, E>
+where
+    E: DeError,
+{
+    match parse(&value) {
+        Some(_) => Ok(value),
+        None => Err(E::custom(format!("invalid jira timestamp {}", value))),
+    }
+}
@@ -0,0 +1,80 @@
+//! Interfaces for accessing and managing epics
+
+// Third party
+use url::form_urlencoded;
+
+// Ours
+use sprints::{MoveIssues, MAX_MOVED_ISSUES};
+use {EmptyResponse, Issue, Jira, Paginated, Result, SearchOptions};
+
+#[derive(Debug)]
+pub struct Epics {
+    jira: Jira,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Epic {
+    pub id: u64,
+    pub key: String,
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub name: String,
+    pub summary: String,
+    pub done: bool,
+}
+
+impl Epics {
+    pub fn new(jira: &Jira) -> Epics {
+        Epics { jira: jira.clone() }
+    }
+
+    /// returns a single epic, by id or key
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/epic-getEpic
+    pub fn get<I>(&self, id: I) -> Result<Epic>
+    where
+        I: ToString,
+    {
+        self.jira.get("agile", &format!("/epic/{}", id.to_string()))
+    }
+
+    /// returns a single page of the issues belonging to an epic
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/epic-getIssuesForEpic
+    pub fn issues<I>(&self, epic_id: I, options: &SearchOptions) -> Result<Paginated<Issue>>
+    where
+        I: ToString,
+    {
+        let mut path = vec![format!("/epic/{}/issue", epic_id.to_string())];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<Issue>>("agile", path.join("?").as_ref())
+    }
+
+    /// moves issues into an epic, 50 issues per request
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/epic-moveIssuesToEpic
+    pub fn move_issues<I>(&self, epic_id: I, issues: Vec<String>) -> Result<EmptyResponse>
+    where
+        I: ToString,
+    {
+        self.move_to(&format!("/epic/{}/issue", epic_id.to_string()), issues)
+    }
+
+    /// removes issues from whichever epic they belong to, 50 issues per request
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/epic-removeIssuesFromEpic
+    pub fn remove_issues(&self, issues: Vec<String>) -> Result<EmptyResponse> {
+        self.move_to("/epic/none/issue", issues)
+    }
+
+    fn move_to(&self, path: &str, issues: Vec<String>) -> Result<EmptyResponse> {
+        for chunk in issues.chunks(MAX_MOVED_ISSUES) {
+            let data = MoveIssues {
+                issues: chunk.to_vec(),
+            };
+            self.jira.post::<EmptyResponse, _>("agile", path, data)?;
+        }
+        Ok(EmptyResponse)
+    }
+}
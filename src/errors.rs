@@ -3,6 +3,7 @@ use reqwest::Error as HttpError;
 use reqwest::StatusCode;
 use serde_json::error::Error as SerdeError;
 use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
 use std::error::Error as StdError;
 
 // Ours
@@ -18,14 +19,30 @@ pub enum Error {
     IO(IoError),
     /// error associated with parsing or serializing
     Serde(SerdeError),
-    /// client request errors
+    /// unsuccessful responses, carrying any messages jira reported
     Fault { code: StatusCode, errors: Errors },
-    /// invalid credentials
-    Unauthorized,
-    /// HTTP method is not allowed
-    MethodNotAllowed,
-    /// Page not found
-    NotFound,
+    /// invalid credentials, carrying any messages jira reported
+    Unauthorized { errors: Errors },
+    /// valid credentials which lack permission for the request, carrying any messages
+    /// jira reported
+    Forbidden { errors: Errors },
+    /// HTTP method is not allowed, carrying any messages jira reported
+    MethodNotAllowed { errors: Errors },
+    /// Page not found, carrying any messages jira reported, e.g. that an issue does not exist
+    NotFound { errors: Errors },
+    /// jira did not respond within the configured timeout
+    Timeout,
+    /// a request could not be built because fields jira requires were not set
+    MissingRequiredFields(Vec<String>),
+    /// an issue type which create metadata does not offer in a project
+    InvalidIssueType { project: String, issue_type: String },
+    /// a successful response which was not json, e.g. a login page or a proxy's error page,
+    /// carrying the start of its body for diagnostics
+    UnexpectedContentType {
+        content_type: String,
+        status: StatusCode,
+        body_snippet: String,
+    },
 }
 
 impl From<SerdeError> for Error {
@@ -36,13 +53,22 @@ impl From<SerdeError> for Error {
 
 impl From<HttpError> for Error {
     fn from(error: HttpError) -> Error {
-        Error::Http(error)
+        if error.is_timeout() {
+            Error::Timeout
+        } else {
+            Error::Http(error)
+        }
     }
 }
 
 impl From<IoError> for Error {
     fn from(error: IoError) -> Error {
-        Error::IO(error)
+        // reading a response body past the timeout surfaces as an io error
+        if error.kind() == IoErrorKind::TimedOut {
+            Error::Timeout
+        } else {
+            Error::IO(error)
+        }
     }
 }
 
@@ -58,34 +84,47 @@ impl ::std::fmt::Display for Error {
                 ref code,
                 ref errors,
             } => writeln!(f, "Jira Client Error ({}):\n{:#?}", code, errors),
-            _ => writeln!(f, "Could not connect to Jira: {}", self.description()),
+            Unauthorized { ref errors } => {
+                writeln!(f, "Jira Client Error (Unauthorized):\n{:#?}", errors)
+            }
+            Forbidden { ref errors } => writeln!(f, "Jira Client Error (Forbidden):\n{:#?}", errors),
+            MethodNotAllowed { ref errors } => {
+                writeln!(f, "Jira Client Error (MethodNotAllowed):\n{:#?}", errors)
+            }
+            NotFound { ref errors } => writeln!(f, "Jira Client Error (NotFound):\n{:#?}", errors),
+            Timeout => writeln!(f, "Could not connect to Jira: Timeout"),
+            MissingRequiredFields(ref fields) => {
+                writeln!(f, "Missing required fields: {}", fields.join(", "))
+            }
+            InvalidIssueType {
+                ref project,
+                ref issue_type,
+            } => writeln!(
+                f,
+                "Issue type {} cannot be created in project {}",
+                issue_type, project
+            ),
+            UnexpectedContentType {
+                ref content_type,
+                ref status,
+                ref body_snippet,
+            } => writeln!(
+                f,
+                "Unexpected content type {} ({}):\n{}",
+                content_type, status, body_snippet
+            ),
         }
     }
 }
 
 impl StdError for Error {
-    fn description(&self) -> &str {
-        use Error::*;
-
-        match *self {
-            Http(ref e) => e.description(),
-            IO(ref e) => e.description(),
-            Serde(ref e) => e.description(),
-            Fault { .. } => "Jira client error",
-            Unauthorized => "Unauthorized",
-            MethodNotAllowed => "MethodNotAllowed",
-            NotFound => "NotFound",
-        }
-    }
-
-    fn cause(&self) -> Option<&StdError> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
         use Error::*;
 
         match *self {
             Http(ref e) => Some(e),
             IO(ref e) => Some(e),
             Serde(ref e) => Some(e),
-            Fault { .. } => None,
             _ => None,
         }
     }
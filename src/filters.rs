@@ -0,0 +1,80 @@
+//! Interfaces for accessing and managing saved filters
+
+// Ours
+use {Jira, Result, User};
+
+/// filter options
+#[derive(Debug)]
+pub struct Filters {
+    jira: Jira,
+}
+
+/// a saved jql search
+#[derive(Deserialize, Debug)]
+pub struct Filter {
+    pub id: String,
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub jql: String,
+    pub owner: Option<User>,
+    /// a link to the filter's search results in the jira ui
+    #[serde(rename = "viewUrl")]
+    pub view_url: Option<String>,
+    /// a link to the filter's search results through the rest api
+    #[serde(rename = "searchUrl")]
+    pub search_url: Option<String>,
+    #[serde(default)]
+    pub favourite: bool,
+}
+
+/// a new saved filter
+#[derive(Serialize, Debug, Default)]
+pub struct CreateFilter {
+    pub name: String,
+    pub jql: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// whether to add the filter to the current user's favourites
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favourite: Option<bool>,
+}
+
+impl CreateFilter {
+    pub fn new<N, J>(name: N, jql: J) -> CreateFilter
+    where
+        N: Into<String>,
+        J: Into<String>,
+    {
+        CreateFilter {
+            name: name.into(),
+            jql: jql.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Filters {
+    pub fn new(jira: &Jira) -> Filters {
+        Filters { jira: jira.clone() }
+    }
+
+    /// returns a single filter
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/filter-getFilter
+    pub fn get(&self, id: u64) -> Result<Filter> {
+        self.jira.get("api", &format!("/filter/{}", id))
+    }
+
+    /// returns the current user's favourite filters
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/filter-getFavouriteFilters
+    pub fn favourites(&self) -> Result<Vec<Filter>> {
+        self.jira.get("api", "/filter/favourite")
+    }
+
+    /// saves a new filter, owned by the current user
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/filter-createFilter
+    pub fn create(&self, filter: CreateFilter) -> Result<Filter> {
+        self.jira.post("api", "/filter", filter)
+    }
+}
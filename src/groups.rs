@@ -0,0 +1,99 @@
+//! Interfaces for managing group membership
+
+// Third party
+use serde_json::Value;
+use url::form_urlencoded;
+
+// Ours
+use {Jira, Paginated, Result, SearchOptions, User};
+
+/// group options
+#[derive(Debug)]
+pub struct Groups {
+    jira: Jira,
+}
+
+#[derive(Serialize, Debug)]
+struct AddUser<'a> {
+    #[serde(rename = "accountId", skip_serializing_if = "Option::is_none")]
+    account_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+}
+
+impl Groups {
+    pub fn new(jira: &Jira) -> Groups {
+        Groups { jira: jira.clone() }
+    }
+
+    /// returns a single page of a group's members. this endpoint is available on jira cloud
+    /// and on jira server from version 7, older servers instead list members when fetching a
+    /// group with an expand of `users`
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/group-getUsersFromGroup
+    pub fn members(&self, group_name: &str, options: &SearchOptions) -> Result<Paginated<User>> {
+        let mut path = vec!["/group/member".to_owned()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("groupname", group_name)
+            .finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<User>>("api", path.join("?").as_ref())
+    }
+
+    /// adds a user, by account id as used by jira cloud, to a group
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-groups/#api-rest-api-2-group-user-post
+    pub fn add_user(&self, group_name: &str, account_id: &str) -> Result<()> {
+        self.add(
+            group_name,
+            AddUser {
+                account_id: Some(account_id),
+                name: None,
+            },
+        )
+    }
+
+    /// adds a user, by username as used by jira server, to a group
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/group-addUserToGroup
+    pub fn add_user_by_username(&self, group_name: &str, username: &str) -> Result<()> {
+        self.add(
+            group_name,
+            AddUser {
+                account_id: None,
+                name: Some(username),
+            },
+        )
+    }
+
+    /// removes a user, by account id as used by jira cloud, from a group
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-groups/#api-rest-api-2-group-user-delete
+    pub fn remove_user(&self, group_name: &str, account_id: &str) -> Result<()> {
+        self.remove(group_name, "accountId", account_id)
+    }
+
+    /// removes a user, by username as used by jira server, from a group
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/group-removeUserFromGroup
+    pub fn remove_user_by_username(&self, group_name: &str, username: &str) -> Result<()> {
+        self.remove(group_name, "username", username)
+    }
+
+    fn add(&self, group_name: &str, user: AddUser) -> Result<()> {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("groupname", group_name)
+            .finish();
+        // jira responds with the updated group, which isn't needed here
+        self.jira
+            .post::<Value, _>("api", &format!("/group/user?{}", query), user)?;
+        Ok(())
+    }
+
+    fn remove(&self, group_name: &str, param: &str, value: &str) -> Result<()> {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("groupname", group_name)
+            .append_pair(param, value)
+            .finish();
+        self.jira.delete("api", &format!("/group/user?{}", query))
+    }
+}
@@ -1,10 +1,29 @@
 //! Interfaces for accessing and managing issues
 
 // Third party
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, FixedOffset, TimeZone};
+use reqwest::multipart::{Form, Part};
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{ser, Serialize};
+use serde_json::{self, json};
+use std::collections::{BTreeMap, HashMap};
+#[cfg(feature = "chrono")]
+use std::fmt::Display;
+use std::io::Read;
 use url::form_urlencoded;
 
 // Ours
-use {Board, Issue, Jira, Result, SearchOptions};
+#[cfg(feature = "chrono")]
+use datetime;
+use pagination::Fetch;
+use {
+    read, respond, AddComment, Attachment, Board, Comments, CreateMeta, Deployment, EditMeta,
+    Error, Errors, Filter, History, Issue, IssueProperty, Jira, LinkType, PageIter, RemoteLinks,
+    Resolution, Result, SearchOptions, Status, TransitionOption, TransitionTriggerOptions, User,
+    ValidateQuery, Version, Votes, VotesInfo, Watchers, Worklogs,
+};
 
 /// issue options
 #[derive(Debug)]
@@ -12,23 +31,60 @@ pub struct Issues {
     jira: Jira,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// refers to a user, e.g. an assignee or reporter. jira server identifies users by
+/// username while jira cloud identifies them by account id
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Assignee {
-    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "accountId", skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+impl Assignee {
+    /// refers to a user by username, as used by jira server
+    pub fn by_name<N>(name: N) -> Assignee
+    where
+        N: Into<String>,
+    {
+        Assignee {
+            name: Some(name.into()),
+            account_id: None,
+        }
+    }
+
+    /// refers to a user by account id, as used by jira cloud
+    pub fn by_account_id<A>(account_id: A) -> Assignee
+    where
+        A: Into<String>,
+    {
+        Assignee {
+            name: None,
+            account_id: Some(account_id.into()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct IssueType {
     pub id: String,
+    #[serde(default, skip_serializing)]
+    pub name: String,
+    #[serde(default, skip_serializing)]
+    pub subtask: bool,
+    #[serde(rename = "iconUrl", default, skip_serializing)]
+    pub icon_url: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// a priority, which need only carry its `id` when creating or editing an issue
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Priority {
     pub id: String,
-    #[serde(rename = "iconUrl")]
+    #[serde(rename = "iconUrl", default, skip_serializing_if = "String::is_empty")]
     pub icon_url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub name: String,
-    #[serde(rename = "self")]
+    #[serde(rename = "self", default, skip_serializing_if = "String::is_empty")]
     pub url: String,
 }
 
@@ -41,28 +97,255 @@ pub struct CustomField {
 }
 
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Project {
     pub key: String,
 }
 
+/// the parent of a subtask
 #[derive(Serialize, Deserialize, Debug)]
+pub struct Parent {
+    pub key: String,
+}
+
+/// a brief reference to another issue, such as one of a parent's subtasks
+#[derive(Deserialize, Debug)]
+pub struct IssueRef {
+    pub id: String,
+    pub key: String,
+    #[serde(rename = "self")]
+    pub self_link: String,
+    #[serde(default)]
+    pub fields: IssueRefFields,
+}
+
+/// the few fields jira includes with an issue reference
+#[derive(Deserialize, Debug, Default)]
+pub struct IssueRefFields {
+    #[serde(default)]
+    pub summary: String,
+    pub status: Option<Status>,
+    pub issuetype: Option<IssueType>,
+    pub priority: Option<Priority>,
+}
+
+/// a project component. create and update bodies may refer to
+/// a component by either its `id` or its `name`
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Component {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub name: String,
+    #[serde(skip_serializing)]
+    pub description: Option<String>,
+    #[serde(skip_serializing)]
+    pub lead: Option<User>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+impl Component {
+    /// refers to a component by name
+    pub fn new<N>(name: N) -> Component
+    where
+        N: Into<String>,
+    {
+        Component {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// refers to a component by id
+    pub fn with_id<I>(id: I) -> Component
+    where
+        I: Into<String>,
+    {
+        Component {
+            id: Some(id.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// an issue security level, restricting who may see an issue. create and update bodies
+/// refer to a level by its `id` alone
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct SecurityLevel {
+    pub id: String,
+    #[serde(default, skip_serializing)]
+    pub name: String,
+    #[serde(default, skip_serializing)]
+    pub description: Option<String>,
+    #[serde(rename = "self", default, skip_serializing)]
+    pub self_link: String,
+}
+
+impl SecurityLevel {
+    /// refers to a security level by id
+    pub fn with_id<I>(id: I) -> SecurityLevel
+    where
+        I: Into<String>,
+    {
+        SecurityLevel {
+            id: id.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// estimated and logged time. estimates are set in jira's duration format, e.g. `3w 2d 4h`,
+/// while the number of seconds and time spent are reported by jira
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeTracking {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_estimate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_estimate: Option<String>,
+    #[serde(skip_serializing)]
+    pub time_spent: Option<String>,
+    #[serde(skip_serializing)]
+    pub original_estimate_seconds: Option<u64>,
+    #[serde(skip_serializing)]
+    pub remaining_estimate_seconds: Option<u64>,
+    #[serde(skip_serializing)]
+    pub time_spent_seconds: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Fields {
-    pub assignee: Assignee,
+    /// omitted when unset, leaving jira to apply the project's default assignee
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Assignee>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub components: Vec<Component>,
-    pub description: String,
-    pub environment: String,
+    /// omitted when creating issues without one, which some instances treat differently
+    /// from an empty description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fix_versions: Vec<Version>,
     pub issuetype: IssueType,
-    pub priority: Priority,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// the parent of a subtask, whose `issuetype` must then be a subtask type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Parent>,
+    /// omitted when creating issues of types which have no priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
     pub project: Project,
-    pub reporter: Assignee,
+    /// omitted when unset, so that jira reports the issue as the authenticated user's.
+    /// setting another reporter requires the modify reporter permission, without which jira
+    /// rejects the issue with an error for this field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reporter: Option<Assignee>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<Resolution>,
+    /// the security level restricting who may see the issue, omitted when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<SecurityLevel>,
+    /// when the issue was created, which is never sent when creating an issue
+    #[serde(default, skip_serializing)]
+    #[cfg_attr(
+        feature = "chrono",
+        serde(deserialize_with = "datetime::deserialize_checked_option")
+    )]
+    pub created: Option<String>,
+    /// the current status, which is never sent when creating an issue
+    #[serde(skip_serializing)]
+    pub status: Option<Status>,
+    /// the issue's subtasks, which are never sent when creating an issue
+    #[serde(default, skip_serializing)]
+    pub subtasks: Vec<IssueRef>,
     pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timetracking: Option<TimeTracking>,
+    /// when the issue was last updated, which is never sent when creating an issue
+    #[serde(default, skip_serializing)]
+    #[cfg_attr(
+        feature = "chrono",
+        serde(deserialize_with = "datetime::deserialize_checked_option")
+    )]
+    pub updated: Option<String>,
+    /// the versions affected by the issue
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub versions: Vec<Version>,
+    /// the issue's vote count, which is never sent when creating an issue
+    #[serde(skip_serializing)]
+    pub votes: Option<VotesInfo>,
+    /// any other fields, typically `customfield_XXXXX` custom fields
+    #[serde(flatten)]
+    pub custom: HashMap<String, ::serde_json::Value>,
+}
+
+impl Fields {
+    /// replaces these fields with any set in `overrides`
+    fn apply(&mut self, overrides: Fields) {
+        fn replace<T>(field: &mut Option<T>, value: Option<T>) {
+            if value.is_some() {
+                *field = value;
+            }
+        }
+        fn replace_all<T>(field: &mut Vec<T>, values: Vec<T>) {
+            if !values.is_empty() {
+                *field = values;
+            }
+        }
+
+        replace(&mut self.assignee, overrides.assignee);
+        replace_all(&mut self.components, overrides.components);
+        replace(&mut self.description, overrides.description);
+        replace(&mut self.environment, overrides.environment);
+        replace_all(&mut self.fix_versions, overrides.fix_versions);
+        if !overrides.issuetype.id.is_empty() {
+            self.issuetype = overrides.issuetype;
+        }
+        replace_all(&mut self.labels, overrides.labels);
+        replace(&mut self.parent, overrides.parent);
+        replace(&mut self.priority, overrides.priority);
+        if !overrides.project.key.is_empty() {
+            self.project = overrides.project;
+        }
+        replace(&mut self.reporter, overrides.reporter);
+        replace(&mut self.resolution, overrides.resolution);
+        replace(&mut self.security, overrides.security);
+        if !overrides.summary.is_empty() {
+            self.summary = overrides.summary;
+        }
+        replace(&mut self.timetracking, overrides.timetracking);
+        replace_all(&mut self.versions, overrides.versions);
+        self.custom.extend(overrides.custom);
+    }
+
+    /// created timestamp, parsed
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created
+            .as_ref()
+            .and_then(|value| datetime::parse(value))
+    }
+
+    /// updated timestamp, parsed
+    #[cfg(feature = "chrono")]
+    pub fn updated_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.updated
+            .as_ref()
+            .and_then(|value| datetime::parse(value))
+    }
+
+    /// resolves a typed custom field, e.g. `customfield_10020`
+    pub fn custom_field<T>(&self, id: &str) -> Option<Result<T>>
+    where
+        T: DeserializeOwned,
+    {
+        self.custom
+            .get(id)
+            .map(|value| Ok(serde_json::value::from_value::<T>(value.clone())?))
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -70,6 +353,423 @@ pub struct CreateIssue {
     pub fields: Fields,
 }
 
+impl CreateIssue {
+    /// returns a builder for a new issue's fields
+    pub fn builder() -> IssueBuilder {
+        IssueBuilder::new()
+    }
+}
+
+/// builds the fields of a new issue, requiring a project, summary and issue type
+#[derive(Debug, Default)]
+pub struct IssueBuilder {
+    project: Option<String>,
+    summary: Option<String>,
+    issue_type: Option<String>,
+    description: Option<String>,
+    assignee: Option<Assignee>,
+    reporter: Option<Assignee>,
+    priority: Option<String>,
+    security: Option<String>,
+    labels: Vec<String>,
+    components: Vec<String>,
+    custom: HashMap<String, ::serde_json::Value>,
+    error: Option<String>,
+}
+
+impl IssueBuilder {
+    pub fn new() -> IssueBuilder {
+        Default::default()
+    }
+
+    /// sets the key of the project to create the issue in
+    pub fn project<K>(&mut self, key: K) -> &mut IssueBuilder
+    where
+        K: Into<String>,
+    {
+        self.project = Some(key.into());
+        self
+    }
+
+    pub fn summary<S>(&mut self, summary: S) -> &mut IssueBuilder
+    where
+        S: Into<String>,
+    {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// sets the id of the issue's type
+    pub fn issue_type<I>(&mut self, id: I) -> &mut IssueBuilder
+    where
+        I: Into<String>,
+    {
+        self.issue_type = Some(id.into());
+        self
+    }
+
+    pub fn description<D>(&mut self, description: D) -> &mut IssueBuilder
+    where
+        D: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn assignee(&mut self, assignee: Assignee) -> &mut IssueBuilder {
+        self.assignee = Some(assignee);
+        self
+    }
+
+    /// reports the issue as someone other than the authenticated user
+    pub fn reporter(&mut self, reporter: Assignee) -> &mut IssueBuilder {
+        self.reporter = Some(reporter);
+        self
+    }
+
+    /// sets the id of the issue's priority
+    pub fn priority<I>(&mut self, id: I) -> &mut IssueBuilder
+    where
+        I: Into<String>,
+    {
+        self.priority = Some(id.into());
+        self
+    }
+
+    /// sets the id of the issue's security level
+    pub fn security<I>(&mut self, id: I) -> &mut IssueBuilder
+    where
+        I: Into<String>,
+    {
+        self.security = Some(id.into());
+        self
+    }
+
+    pub fn labels<L>(&mut self, labels: Vec<L>) -> &mut IssueBuilder
+    where
+        L: Into<String>,
+    {
+        self.labels = labels.into_iter().map(|l| l.into()).collect();
+        self
+    }
+
+    /// sets the issue's components by name
+    pub fn components<C>(&mut self, names: Vec<C>) -> &mut IssueBuilder
+    where
+        C: Into<String>,
+    {
+        self.components = names.into_iter().map(|c| c.into()).collect();
+        self
+    }
+
+    /// sets a custom field, e.g. `customfield_10020`. a value which fails to serialize is
+    /// reported by `build`
+    pub fn custom_field<N, V>(&mut self, name: N, value: V) -> &mut IssueBuilder
+    where
+        N: Into<String>,
+        V: Serialize,
+    {
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.custom.insert(name.into(), value);
+            }
+            Err(err) => {
+                if self.error.is_none() {
+                    self.error = Some(err.to_string());
+                }
+            }
+        }
+        self
+    }
+
+    /// checks the issue against create metadata, e.g. from `Issues::create_meta`, before any
+    /// request is made. fails with `Error::InvalidIssueType` when the project does not offer
+    /// the issue type, or `Error::MissingRequiredFields` naming, as jira displays them, any
+    /// required fields without a default which were not set
+    pub fn validate_against(&self, meta: &CreateMeta) -> Result<&IssueBuilder> {
+        let missing = self.missing();
+        if !missing.is_empty() {
+            return Err(Error::MissingRequiredFields(missing));
+        }
+
+        let project = self.project.clone().unwrap_or_default();
+        let issue_type = self.issue_type.clone().unwrap_or_default();
+        let fields = meta
+            .projects
+            .iter()
+            .find(|p| p.key == project)
+            .and_then(|p| p.issuetypes.iter().find(|t| t.id == issue_type))
+            .map(|t| &t.fields)
+            .ok_or_else(|| Error::InvalidIssueType {
+                project: project.clone(),
+                issue_type: issue_type.clone(),
+            })?;
+        let missing = fields
+            .iter()
+            .filter(|(id, field)| {
+                field.required && !field.has_default_value.unwrap_or(false) && !self.is_set(id)
+            })
+            .map(|(_, field)| field.name.clone())
+            .collect::<Vec<String>>();
+        if !missing.is_empty() {
+            return Err(Error::MissingRequiredFields(missing));
+        }
+        Ok(self)
+    }
+
+    /// returns the new issue, `Error::Serde` when a custom field failed to serialize, or
+    /// `Error::MissingRequiredFields` naming any of `project`, `summary` and `issuetype` which
+    /// were not set
+    pub fn build(&self) -> Result<CreateIssue> {
+        if let Some(ref err) = self.error {
+            return Err(Error::Serde(ser::Error::custom(err)));
+        }
+        let missing = self.missing();
+        if !missing.is_empty() {
+            return Err(Error::MissingRequiredFields(missing));
+        }
+
+        Ok(CreateIssue {
+            fields: Fields {
+                assignee: self.assignee.clone(),
+                components: self
+                    .components
+                    .iter()
+                    .map(|name| Component {
+                        name: name.clone(),
+                        ..Default::default()
+                    })
+                    .collect(),
+                description: self.description.clone(),
+                issuetype: IssueType {
+                    id: self.issue_type.clone().unwrap_or_default(),
+                    ..Default::default()
+                },
+                labels: self.labels.clone(),
+                priority: self.priority.clone().map(|id| Priority {
+                    id,
+                    ..Default::default()
+                }),
+                project: Project {
+                    key: self.project.clone().unwrap_or_default(),
+                },
+                reporter: self.reporter.clone(),
+                security: self.security.clone().map(SecurityLevel::with_id),
+                summary: self.summary.clone().unwrap_or_default(),
+                custom: self.custom.clone(),
+                ..Default::default()
+            },
+        })
+    }
+
+    /// the fields every issue needs which were not set
+    fn missing(&self) -> Vec<String> {
+        [
+            ("project", &self.project),
+            ("summary", &self.summary),
+            ("issuetype", &self.issue_type),
+        ]
+        .iter()
+        .filter(|(_, value)| value.is_none())
+        .map(|(name, _)| name.to_string())
+        .collect()
+    }
+
+    /// whether a field, by id, was set
+    fn is_set(&self, id: &str) -> bool {
+        match id {
+            "project" => self.project.is_some(),
+            "summary" => self.summary.is_some(),
+            "issuetype" => self.issue_type.is_some(),
+            "description" => self.description.is_some(),
+            "assignee" => self.assignee.is_some(),
+            // jira reports the authenticated user when no reporter is set
+            "reporter" => true,
+            "priority" => self.priority.is_some(),
+            "security" => self.security.is_some(),
+            "labels" => !self.labels.is_empty(),
+            "components" => !self.components.is_empty(),
+            _ => self.custom.contains_key(id),
+        }
+    }
+}
+
+/// a partial set of fields to edit on an existing issue.
+/// fields left as `None` are left untouched on the server
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Assignee>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(rename = "fixVersions", skip_serializing_if = "Option::is_none")]
+    pub fix_versions: Option<Vec<Version>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuetype: Option<IssueType>,
+    /// replaces all of an issue's labels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reporter: Option<Assignee>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<SecurityLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timetracking: Option<TimeTracking>,
+    /// replaces all of the versions affected by an issue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub versions: Option<Vec<Version>>,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateIssue {
+    pub fields: UpdateFields,
+    /// incremental edits, keyed by field name, e.g. adding a single label
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub update: BTreeMap<String, Vec<FieldOperation>>,
+}
+
+/// an incremental edit of a field's value
+#[derive(Serialize, Debug)]
+pub enum FieldOperation {
+    #[serde(rename = "add")]
+    Add(::serde_json::Value),
+    #[serde(rename = "remove")]
+    Remove(::serde_json::Value),
+    #[serde(rename = "set")]
+    Set(::serde_json::Value),
+    /// changes part of a value, e.g. one of the estimates of `timetracking`
+    #[serde(rename = "edit")]
+    Edit(::serde_json::Value),
+}
+
+/// a link to create between two issues, e.g. `inward_issue` "is blocked by" `outward_issue`
+#[derive(Debug)]
+pub struct LinkIssues {
+    /// the name of the link type, e.g. `Blocks`
+    pub link_type: String,
+    pub inward_issue: String,
+    pub outward_issue: String,
+    /// a comment to add to the outward issue
+    pub comment: Option<AddComment>,
+}
+
+impl LinkIssues {
+    pub fn new<T, I, O>(link_type: T, inward_issue: I, outward_issue: O) -> LinkIssues
+    where
+        T: Into<String>,
+        I: Into<String>,
+        O: Into<String>,
+    {
+        LinkIssues {
+            link_type: link_type.into(),
+            inward_issue: inward_issue.into(),
+            outward_issue: outward_issue.into(),
+            comment: None,
+        }
+    }
+}
+
+/// the body of a link request, as jira expects it
+#[derive(Serialize, Debug)]
+struct LinkBody {
+    #[serde(rename = "type")]
+    link_type: Named,
+    #[serde(rename = "inwardIssue")]
+    inward_issue: Keyed,
+    #[serde(rename = "outwardIssue")]
+    outward_issue: Keyed,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<AddComment>,
+}
+
+#[derive(Serialize, Debug)]
+struct Named {
+    name: String,
+}
+
+#[derive(Serialize, Debug)]
+struct Keyed {
+    key: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct LinkTypes {
+    #[serde(rename = "issueLinkTypes")]
+    issue_link_types: Vec<LinkType>,
+}
+
+/// ranks issues relative to another issue on agile boards
+#[derive(Debug)]
+pub struct RankIssues {
+    issues: Vec<String>,
+    position: RankPosition,
+}
+
+#[derive(Serialize, Debug, Clone)]
+enum RankPosition {
+    #[serde(rename = "rankBeforeIssue")]
+    Before(String),
+    #[serde(rename = "rankAfterIssue")]
+    After(String),
+}
+
+impl RankIssues {
+    /// ranks `issues`, in order, immediately before `issue`
+    pub fn before<I>(issues: Vec<String>, issue: I) -> RankIssues
+    where
+        I: Into<String>,
+    {
+        RankIssues {
+            issues,
+            position: RankPosition::Before(issue.into()),
+        }
+    }
+
+    /// ranks `issues`, in order, immediately after `issue`
+    pub fn after<I>(issues: Vec<String>, issue: I) -> RankIssues
+    where
+        I: Into<String>,
+    {
+        RankIssues {
+            issues,
+            position: RankPosition::After(issue.into()),
+        }
+    }
+}
+
+/// the most issues jira will rank in a single request
+const MAX_RANKED_ISSUES: usize = 50;
+
+#[derive(Serialize, Debug)]
+struct RankBody<'a> {
+    issues: &'a [String],
+    #[serde(flatten)]
+    position: &'a RankPosition,
+}
+
+/// reported when only some issues could be ranked
+#[derive(Deserialize, Debug)]
+struct RankResults {
+    #[serde(default)]
+    entries: Vec<RankEntry>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RankEntry {
+    #[serde(rename = "issueKey")]
+    issue_key: Option<String>,
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateResponse {
     pub id: String,
@@ -78,15 +778,105 @@ pub struct CreateResponse {
     pub url: String,
 }
 
+/// the outcome of creating issues in bulk. issues which could not be
+/// created are reported in `errors` rather than failing the whole request
+#[derive(Deserialize, Debug, Default)]
+pub struct BulkCreateResponse {
+    #[serde(default)]
+    pub issues: Vec<CreateResponse>,
+    #[serde(default)]
+    pub errors: Vec<BulkCreateError>,
+}
+
+/// the outcome of transitioning issues in bulk, keyed by issue. an issue which
+/// could not be transitioned does not stop the others
+#[derive(Debug, Default)]
+pub struct BulkTransitionResult {
+    pub results: BTreeMap<String, Result<()>>,
+}
+
+impl BulkTransitionResult {
+    /// true when every issue was transitioned
+    pub fn is_success(&self) -> bool {
+        self.results.values().all(|result| result.is_ok())
+    }
+
+    /// the issues which could not be transitioned, with the reason why
+    pub fn failures(&self) -> impl Iterator<Item = (&String, &Error)> {
+        self.results
+            .iter()
+            .filter_map(|(key, result)| result.as_ref().err().map(|e| (key, e)))
+    }
+}
+
+/// describes why one of the issues in a bulk request could not be created
+#[derive(Deserialize, Debug)]
+pub struct BulkCreateError {
+    pub status: Option<u16>,
+    #[serde(rename = "elementErrors")]
+    pub element_errors: Errors,
+    /// the position of the failed issue within the issues passed to `Issues::bulk_create`
+    #[serde(rename = "failedElementNumber")]
+    pub failed_element_number: usize,
+}
+
+/// the most issues jira will create in a single request
+const MAX_BULK_ISSUES: usize = 50;
+
+#[derive(Serialize, Debug)]
+struct BulkCreate<'a> {
+    #[serde(rename = "issueUpdates")]
+    issue_updates: &'a [CreateIssue],
+}
+
+#[derive(Serialize, Debug)]
+struct ArchiveIssues<'a> {
+    #[serde(rename = "issueIdsOrKeys")]
+    issue_ids_or_keys: &'a [String],
+}
+
+/// the outcome of archiving or restoring issues. issues which could not be
+/// updated are grouped in `errors` by the reason jira gives, e.g. `issuesNotFound`
+#[derive(Deserialize, Debug)]
+pub struct ArchiveResult {
+    #[serde(rename = "numberOfIssuesUpdated", default)]
+    pub number_archived: u64,
+    #[serde(default)]
+    pub errors: BTreeMap<String, ArchiveError>,
+}
+
+/// issues which could not be archived or restored for the same reason
+#[derive(Deserialize, Debug)]
+pub struct ArchiveError {
+    #[serde(default)]
+    pub count: u64,
+    #[serde(rename = "issueIdsOrKeys", default)]
+    pub issue_ids_or_keys: Vec<String>,
+    pub message: Option<String>,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Paginated<T> {
-    pub expand: String,
+    pub expand: Option<String>,
     #[serde(rename = "maxResults")]
     pub max_results: u64,
     #[serde(rename = "startAt")]
     pub start_at: u64,
+    /// not reported by agile endpoints, which report `is_last` instead
+    #[serde(default)]
     pub total: u64,
+    #[serde(rename = "isLast")]
+    pub is_last: Option<bool>,
+    #[serde(
+        alias = "issues",
+        alias = "comments",
+        alias = "worklogs",
+        alias = "dashboards"
+    )]
     pub values: Vec<T>,
+    /// problems with a search's jql, reported when validating with `ValidateQuery::Warn`
+    #[serde(rename = "warningMessages", default)]
+    pub warning_messages: Vec<String>,
 }
 
 impl Issues {
@@ -100,10 +890,478 @@ impl Issues {
     {
         self.jira.get("api", &format!("/issue/{}", id.into()))
     }
+
+    /// returns a single issue, limited to the `fields` and extended with the `expand`
+    /// search options, e.g. `changelog` or `renderedFields`
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getIssue
+    pub fn get_with_options<I>(&self, id: I, options: &SearchOptions) -> Result<Issue>
+    where
+        I: Into<String>,
+    {
+        let mut path = vec![format!("/issue/{}", id.into())];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira.get::<Issue>("api", path.join("?").as_ref())
+    }
+
+    /// returns a single issue with only the given `fields`, which may include `*all` or
+    /// `*navigable`, the given `expand` options and the issue `properties` with the given keys.
+    /// empty slices fall back to jira's defaults
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getIssue
+    pub fn get_with<I>(
+        &self,
+        id: I,
+        fields: &[&str],
+        expand: &[&str],
+        properties: &[&str],
+    ) -> Result<Issue>
+    where
+        I: Into<String>,
+    {
+        let mut builder = SearchOptions::builder();
+        if !fields.is_empty() {
+            builder.fields(fields.to_vec());
+        }
+        if !expand.is_empty() {
+            builder.expand(expand.to_vec());
+        }
+        if !properties.is_empty() {
+            builder.properties(properties.to_vec());
+        }
+        self.get_with_options(id, &builder.build())
+    }
+
     pub fn create(&self, data: CreateIssue) -> Result<CreateResponse> {
         self.jira.post("api", "/issue", data)
     }
 
+    /// creates a copy of an issue, carrying over its project, issue type, summary, description,
+    /// components and priority. fields set in `overrides` replace those copied, and unless
+    /// `overrides` sets a summary the copied summary is prefixed with `CLONE - `
+    pub fn clone<I>(&self, id: I, overrides: Option<Fields>) -> Result<CreateResponse>
+    where
+        I: Into<String>,
+    {
+        let source = self.get(id)?;
+        let mut fields = Fields {
+            components: copied(&source, "components")?,
+            issuetype: copied(&source, "issuetype")?,
+            priority: copied(&source, "priority")?,
+            project: copied(&source, "project")?,
+            ..Default::default()
+        };
+        if let Some(overrides) = overrides {
+            fields.apply(overrides);
+        }
+        if fields.summary.is_empty() {
+            fields.summary = format!("CLONE - {}", copied::<String>(&source, "summary")?);
+        }
+        if fields.description.is_none() && !fields.custom.contains_key("description") {
+            // version 3 of the api describes issues with atlassian document format documents,
+            // which are copied as they are
+            match copied::<Option<serde_json::Value>>(&source, "description")? {
+                Some(serde_json::Value::String(text)) => fields.description = Some(text),
+                Some(document) => {
+                    fields.custom.insert("description".to_owned(), document);
+                }
+                None => (),
+            }
+        }
+        self.create(CreateIssue { fields })
+    }
+
+    /// creates many issues at once, sending them in batches of the 50 jira accepts per request.
+    /// a batch in which every issue fails is reported by jira, and so here, as an error
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-createIssues
+    pub fn bulk_create(&self, issues: Vec<CreateIssue>) -> Result<BulkCreateResponse> {
+        let mut response = BulkCreateResponse::default();
+        for (batch, chunk) in issues.chunks(MAX_BULK_ISSUES).enumerate() {
+            let data = BulkCreate {
+                issue_updates: chunk,
+            };
+            let created = self
+                .jira
+                .post::<BulkCreateResponse, _>("api", "/issue/bulk", data)?;
+            response.issues.extend(created.issues);
+            // jira numbers failures within each request
+            response
+                .errors
+                .extend(created.errors.into_iter().map(|mut error| {
+                    error.failed_element_number += batch * MAX_BULK_ISSUES;
+                    error
+                }));
+        }
+        Ok(response)
+    }
+
+    /// describes the fields required to create issues, per project and issue type.
+    /// narrow the results with the `project_keys` and `issue_type_names` search options
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getCreateIssueMeta
+    pub fn create_meta(&self, options: &SearchOptions) -> Result<CreateMeta> {
+        let mut path = vec!["/issue/createmeta".to_owned()];
+        let query_options = options
+            .as_builder()
+            .expand(vec!["projects.issuetypes.fields"])
+            .build()
+            .serialize()
+            .unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira
+            .get_cached::<CreateMeta>("api", path.join("?").as_ref())
+    }
+
+    /// describes the fields which may currently be edited on an issue, and how
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getEditIssueMeta
+    pub fn edit_meta<I>(&self, id: I) -> Result<EditMeta>
+    where
+        I: Into<String>,
+    {
+        self.jira
+            .get("api", &format!("/issue/{}/editmeta", id.into()))
+    }
+
+    /// edits the fields of an existing issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-editIssue
+    pub fn update<I>(&self, id: I, data: UpdateIssue) -> Result<()>
+    where
+        I: Into<String>,
+    {
+        self.jira.put("api", &format!("/issue/{}", id.into()), data)
+    }
+
+    /// adds a label to an issue, leaving its other labels in place
+    pub fn add_label<I, L>(&self, id: I, label: L) -> Result<()>
+    where
+        I: Into<String>,
+        L: Into<String>,
+    {
+        self.update_labels(id, FieldOperation::Add(label.into().into()))
+    }
+
+    /// removes a label from an issue, leaving its other labels in place
+    pub fn remove_label<I, L>(&self, id: I, label: L) -> Result<()>
+    where
+        I: Into<String>,
+        L: Into<String>,
+    {
+        self.update_labels(id, FieldOperation::Remove(label.into().into()))
+    }
+
+    fn update_labels<I>(&self, id: I, operation: FieldOperation) -> Result<()>
+    where
+        I: Into<String>,
+    {
+        let mut data = UpdateIssue::default();
+        data.update.insert("labels".to_owned(), vec![operation]);
+        self.update(id, data)
+    }
+
+    /// sets the remaining estimate of an issue, in jira's duration format, e.g. `2h`,
+    /// leaving its original estimate in place
+    pub fn set_remaining_estimate<I>(&self, id: I, estimate: &str) -> Result<()>
+    where
+        I: Into<String>,
+    {
+        let mut data = UpdateIssue::default();
+        data.update.insert(
+            "timetracking".to_owned(),
+            vec![FieldOperation::Edit(
+                json!({ "remainingEstimate": estimate }),
+            )],
+        );
+        self.update(id, data)
+    }
+
+    /// assigns an issue to a user, or unassigns it when `assignee` is `None`
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-assign
+    pub fn assign<I>(&self, id: I, assignee: Option<Assignee>) -> Result<()>
+    where
+        I: Into<String>,
+    {
+        let path = format!("/issue/{}/assignee", id.into());
+        match assignee {
+            Some(assignee) => self.jira.put("api", &path, assignee),
+            None => self
+                .jira
+                .put("api", &path, unassigned(self.jira.deployment())),
+        }
+    }
+
+    /// deletes an issue. issues with subtasks may only be deleted when
+    /// `delete_subtasks` is true, otherwise jira responds with a `Error::Fault`
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-deleteIssue
+    pub fn delete<I>(&self, id: I, delete_subtasks: bool) -> Result<()>
+    where
+        I: Into<String>,
+    {
+        self.jira.delete(
+            "api",
+            &format!("/issue/{}?deleteSubtasks={}", id.into(), delete_subtasks),
+        )
+    }
+
+    /// attaches a file to an issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue/{issueIdOrKey}/attachments-addAttachment
+    pub fn add_attachment<I>(&self, id: I, filename: &str, bytes: Vec<u8>) -> Result<Vec<Attachment>>
+    where
+        I: Into<String>,
+    {
+        let url = self
+            .jira
+            .url("api", &format!("/issue/{}/attachments", id.into()));
+        let res = self.jira.send(Method::POST, &url, |req| {
+            let part = Part::bytes(bytes.clone()).file_name(filename.to_owned());
+            req.header("X-Atlassian-Token", "no-check")
+                .multipart(Form::new().part("file", part))
+        })?;
+        read(res)
+    }
+
+    /// downloads the content of an attachment, from its `content` url
+    pub fn download_attachment(&self, content_url: &str) -> Result<Vec<u8>> {
+        // attachments may be of any type, so no particular content is asked for
+        let mut res = self
+            .jira
+            .send_accepting(Method::GET, content_url, "*/*", |req| req)?;
+        let mut bytes = vec![];
+        res.read_to_end(&mut bytes)?;
+        if res.status().is_success() {
+            Ok(bytes)
+        } else {
+            respond(res.status(), None, &String::from_utf8_lossy(&bytes))
+        }
+    }
+
+    /// links two issues
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issueLink-linkIssues
+    pub fn link(&self, link: LinkIssues) -> Result<()> {
+        let data = LinkBody {
+            link_type: Named {
+                name: link.link_type,
+            },
+            inward_issue: Keyed {
+                key: link.inward_issue,
+            },
+            outward_issue: Keyed {
+                key: link.outward_issue,
+            },
+            comment: link.comment,
+        };
+        self.jira.post("api", "/issueLink", data)
+    }
+
+    /// returns the types of links available between issues
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issueLinkType-getIssueLinkTypes
+    pub fn link_types(&self) -> Result<Vec<LinkType>> {
+        self.jira
+            .get::<LinkTypes>("api", "/issueLinkType")
+            .map(|types| types.issue_link_types)
+    }
+
+    /// ranks issues before or after another issue, 50 issues per request.
+    /// issues which could not be ranked are reported as a `Fault`
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/issue-rankIssues
+    pub fn rank(&self, rank: RankIssues) -> Result<()> {
+        let mut position = rank.position;
+        for chunk in rank.issues.chunks(MAX_RANKED_ISSUES) {
+            let data = RankBody {
+                issues: chunk,
+                position: &position,
+            };
+            let results = self
+                .jira
+                .put::<Option<RankResults>, _>("agile", "/issue/rank", data)?;
+            let messages = results
+                .into_iter()
+                .flat_map(|results| results.entries)
+                .flat_map(|entry| {
+                    let key = entry.issue_key.unwrap_or_default();
+                    entry
+                        .errors
+                        .into_iter()
+                        .map(move |error| format!("{}: {}", key, error))
+                })
+                .collect::<Vec<_>>();
+            if !messages.is_empty() {
+                return Err(Error::Fault {
+                    code: StatusCode::MULTI_STATUS,
+                    errors: Errors {
+                        error_messages: messages,
+                        ..Default::default()
+                    },
+                });
+            }
+            // later batches follow the last issue ranked, keeping the issues in order
+            if let RankPosition::After(_) = position {
+                position = RankPosition::After(chunk[chunk.len() - 1].clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// returns a single page of the changes made to an issue. older jira server instances
+    /// lack this endpoint, instead include the changelog when fetching an issue with
+    /// `get_with_options` and an expand of `changelog`
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-issues/#api-rest-api-2-issue-issueidorkey-changelog-get
+    pub fn changelog<I>(&self, id: I, options: &SearchOptions) -> Result<Paginated<History>>
+    where
+        I: Into<String>,
+    {
+        let mut path = vec![format!("/issue/{}/changelog", id.into())];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<History>>("api", path.join("?").as_ref())
+    }
+
+    /// return comments interface for an issue
+    pub fn comments<I>(&self, id: I) -> Comments
+    where
+        I: Into<String>,
+    {
+        Comments::new(&self.jira, id)
+    }
+
+    /// return worklogs interface for an issue
+    pub fn worklogs<I>(&self, id: I) -> Worklogs
+    where
+        I: Into<String>,
+    {
+        Worklogs::new(&self.jira, id)
+    }
+
+    /// returns users who may be assigned an issue and whose display name or email address
+    /// matches a query, as supported by jira cloud
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-user-search/#api-rest-api-2-user-assignable-search-get
+    pub fn assignable_users(&self, issue_key: &str, query: &str) -> Result<Vec<User>> {
+        self.assignable(issue_key, "query", query)
+    }
+
+    /// returns users who may be assigned an issue and whose username, name or email address
+    /// matches a query, as supported by jira server
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/user-findAssignableUsers
+    pub fn assignable_users_by_username(
+        &self,
+        issue_key: &str,
+        username: &str,
+    ) -> Result<Vec<User>> {
+        self.assignable(issue_key, "username", username)
+    }
+
+    /// returns users who may be assigned an issue and who match a query, searching by
+    /// username on jira server and by display name or email address on jira cloud, as decided
+    /// by `Jira::deployment`
+    pub fn find_assignable(&self, issue_key: &str, query: &str) -> Result<Vec<User>> {
+        self.assignable(issue_key, self.jira.deployment().user_query(), query)
+    }
+
+    fn assignable(&self, issue_key: &str, param: &str, value: &str) -> Result<Vec<User>> {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("issueKey", issue_key)
+            .append_pair(param, value)
+            .finish();
+        self.jira.get("api", &format!("/user/assignable/search?{}", query))
+    }
+
+    /// return watchers interface for an issue
+    pub fn watchers<I>(&self, id: I) -> Watchers
+    where
+        I: Into<String>,
+    {
+        Watchers::new(&self.jira, id)
+    }
+
+    /// return remote links interface for an issue
+    pub fn remote_links<I>(&self, id: I) -> RemoteLinks
+    where
+        I: Into<String>,
+    {
+        RemoteLinks::new(&self.jira, id)
+    }
+
+    /// return votes interface for an issue
+    pub fn votes<I>(&self, id: I) -> Votes
+    where
+        I: Into<String>,
+    {
+        Votes::new(&self.jira, id)
+    }
+
+    /// return interface for a property stored on an issue
+    pub fn property<I>(&self, id: I, key: &str) -> IssueProperty
+    where
+        I: Into<String>,
+    {
+        IssueProperty::new(&self.jira, id, key)
+    }
+
+    /// return list of transitions options for an issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getTransitions
+    pub fn transitions<I>(&self, id: I) -> Result<Vec<TransitionOption>>
+    where
+        I: Into<String>,
+    {
+        self.jira.transitions(id).list()
+    }
+
+    /// moves an issue through its workflow.
+    /// to transition with a resolution use TransitionTriggerOptions::builder(id).resolution(name)
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-doTransition
+    pub fn transition<I>(&self, id: I, trans: TransitionTriggerOptions) -> Result<()>
+    where
+        I: Into<String>,
+    {
+        self.jira.transitions(id).trigger(trans)
+    }
+
+    /// archives issues, which is available on jira cloud premium
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v3/api-group-issues/#api-rest-api-3-issue-archive-put
+    pub fn archive(&self, keys: &[String]) -> Result<ArchiveResult> {
+        self.jira.put(
+            "api",
+            "/issue/archive",
+            ArchiveIssues {
+                issue_ids_or_keys: keys,
+            },
+        )
+    }
+
+    /// restores archived issues, which is available on jira cloud premium
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v3/api-group-issues/#api-rest-api-3-issue-unarchive-put
+    pub fn restore(&self, keys: &[String]) -> Result<ArchiveResult> {
+        self.jira.put(
+            "api",
+            "/issue/unarchive",
+            ArchiveIssues {
+                issue_ids_or_keys: keys,
+            },
+        )
+    }
+
+    /// moves many issues through the same transition. jira has no bulk endpoint so each
+    /// issue is transitioned in turn, and failures are collected rather than aborting
+    pub fn bulk_transition(
+        &self,
+        keys: &[String],
+        trans: TransitionTriggerOptions,
+    ) -> Result<BulkTransitionResult> {
+        let mut result = BulkTransitionResult::default();
+        for key in keys {
+            let outcome = self.transition(key.as_str(), trans.clone());
+            result.results.insert(key.clone(), outcome);
+        }
+        Ok(result)
+    }
+
     /// returns a single page of issues results
     /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-getIssuesForBoard
     pub fn list(&self, board: &Board, options: &SearchOptions) -> Result<Paginated<Issue>> {
@@ -117,61 +1375,227 @@ impl Issues {
             .get::<Paginated<Issue>>("agile", path.join("?").as_ref())
     }
 
-    /// runs a type why may be used to iterate over consecutive pages of results
+    /// returns a single page of issues matching a jql query
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/search-search
+    pub fn search(&self, jql: &str, options: &SearchOptions) -> Result<Paginated<Issue>> {
+        let mut path = vec!["/search".to_owned()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("jql", jql)
+            .finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<Issue>>("api", path.join("?").as_ref())
+    }
+
+    /// returns a single page of issues matching a jql query which were updated at or after
+    /// `since`, with their changelogs expanded for incremental syncing. jira reads jql dates
+    /// in the searching user's time zone, so `since` is converted to `zone` first, which
+    /// should be the one named by `myself().timezone`
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/search-search
+    #[cfg(feature = "chrono")]
+    pub fn search_updated_since<Tz, Z>(
+        &self,
+        jql_base: &str,
+        since: DateTime<Tz>,
+        zone: &Z,
+        options: &SearchOptions,
+    ) -> Result<Paginated<Issue>>
+    where
+        Tz: TimeZone,
+        Z: TimeZone,
+        Z::Offset: Display,
+    {
+        let mut expand = options.expand();
+        if !expand.contains(&"changelog") {
+            expand.push("changelog");
+        }
+        let options = options.as_builder().expand(expand).build();
+        self.search(&updated_since(jql_base, &since, zone), &options)
+    }
+
+    /// returns the number of issues matching a jql query, without fetching any of them
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/search-search
+    pub fn count(&self, jql: &str) -> Result<u64> {
+        let options = SearchOptions::builder()
+            .max_results(0)
+            .fields(Vec::<String>::new())
+            .build();
+        let query = form_urlencoded::Serializer::new(options.serialize().unwrap_or_default())
+            .append_pair("jql", jql)
+            .finish();
+
+        self.jira
+            .get::<SearchTotal>("api", &format!("/search?{}", query))
+            .map(|page| page.total)
+    }
+
+    /// checks a jql query without fetching any issues, returning jira's error messages
+    /// when it is invalid and any warnings otherwise
+    pub fn validate_jql(&self, jql: &str) -> Result<Vec<String>> {
+        let options = SearchOptions::builder()
+            .query_validation(ValidateQuery::Strict)
+            .max_results(0)
+            .build();
+        match self.search(jql, &options) {
+            Ok(page) => Ok(page.warning_messages),
+            Err(Error::Fault { code, errors }) if code == StatusCode::BAD_REQUEST => {
+                Ok(errors.error_messages)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// returns a single page of issues matching a saved filter's jql query
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/search-search
+    pub fn search_filter(
+        &self,
+        filter: &Filter,
+        options: &SearchOptions,
+    ) -> Result<Paginated<Issue>> {
+        self.search(&filter.jql, options)
+    }
+
+    /// runs a type why may be used to iterate over consecutive pages of results.
+    /// no request is made until the iterator is first advanced
     /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-getIssuesForBoard
-    pub fn iter<'a>(&self, board: &'a Board, options: &'a SearchOptions) -> Result<IssuesIter<'a>> {
-        IssuesIter::new(board, options, &self.jira)
+    pub fn iter<'a>(&self, board: &'a Board, options: &'a SearchOptions) -> IssuesIter<'a> {
+        let jira = self.jira.clone();
+        IssuesIter::new(
+            Box::new(move |options| jira.issues().list(board, options)),
+            options,
+        )
+    }
+
+    /// runs a type which may be used to iterate over consecutive pages of jql search results.
+    /// no request is made until the iterator is first advanced
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/search-search
+    pub fn search_iter<'a>(&self, jql: &str, options: &'a SearchOptions) -> IssuesIter<'a> {
+        let jira = self.jira.clone();
+        let jql = jql.to_owned();
+        IssuesIter::new(
+            Box::new(move |options| jira.issues().search(&jql, options)),
+            options,
+        )
+    }
+
+    /// returns every issue on a board, fetching consecutive pages of results until the
+    /// first error
+    pub fn list_all(&self, board: &Board, options: &SearchOptions) -> Result<Vec<Issue>> {
+        self.iter(board, options).collect()
     }
+
+    /// returns every issue matching a jql query, fetching consecutive pages of results until
+    /// the first error
+    pub fn search_all(&self, jql: &str, options: &SearchOptions) -> Result<Vec<Issue>> {
+        self.search_iter(jql, options).collect()
+    }
+}
+
+/// restricts a jql query to issues updated at or after a timestamp, keeping any trailing
+/// `order by` clause last
+#[cfg(feature = "chrono")]
+fn updated_since<Tz, Z>(jql: &str, since: &DateTime<Tz>, zone: &Z) -> String
+where
+    Tz: TimeZone,
+    Z: TimeZone,
+    Z::Offset: Display,
+{
+    let clause = format!("updated >= \"{}\"", datetime::jql(since, zone));
+    let (filter, order) = match order_by(jql) {
+        Some(at) => (jql[..at].trim(), jql[at..].trim()),
+        None => (jql.trim(), ""),
+    };
+    let mut query = if filter.is_empty() {
+        clause
+    } else {
+        format!("({}) AND {}", filter, clause)
+    };
+    if !order.is_empty() {
+        query.push(' ');
+        query.push_str(order);
+    }
+    query
+}
+
+/// the position of the last `ORDER BY` in a jql query, skipping any inside quoted values
+#[cfg(feature = "chrono")]
+fn order_by(jql: &str) -> Option<usize> {
+    let lower = jql.to_ascii_lowercase();
+    let mut quote = None;
+    let mut escaped = false;
+    let mut found = None;
+    for (at, c) in lower.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => (),
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if lower[at..].starts_with("order by") => found = Some(at),
+            None => (),
+        }
+    }
+    found
+}
+
+/// the body which clears the assignee of an issue, naming no user by the identifier the
+/// deployment expects
+fn unassigned(deployment: Deployment) -> serde_json::Value {
+    match deployment {
+        Deployment::Server => json!({ "name": null }),
+        Deployment::Cloud => json!({ "accountId": null }),
+    }
+}
+
+/// copies a field of an existing issue, defaulting when the issue lacks it
+fn copied<F>(issue: &Issue, name: &str) -> Result<F>
+where
+    F: DeserializeOwned + Default,
+{
+    issue.field::<F>(name).unwrap_or_else(|| Ok(F::default()))
+}
+
+/// the total of a page of search results, ignoring any issues
+#[derive(Deserialize, Debug)]
+struct SearchTotal {
+    total: u64,
 }
 
 /// provides an iterator over multiple pages of search results
 #[derive(Debug)]
 pub struct IssuesIter<'a> {
-    jira: Jira,
-    board: &'a Board,
-    results: Paginated<Issue>,
-    search_options: &'a SearchOptions,
+    pages: PageIter<Issue, Fetch<'a, Issue>>,
 }
 
 impl<'a> IssuesIter<'a> {
-    fn new(board: &'a Board, options: &'a SearchOptions, jira: &Jira) -> Result<Self> {
-        let results = jira.issues().list(board, options)?;
-        Ok(IssuesIter {
-            board,
-            jira: jira.clone(),
-            results,
-            search_options: options,
-        })
+    fn new(fetch: Fetch<'a, Issue>, options: &'a SearchOptions) -> Self {
+        IssuesIter {
+            pages: PageIter::new(options, fetch),
+        }
+    }
+
+    /// the total number of matching issues, known once the first page is fetched
+    pub fn total(&self) -> u64 {
+        self.pages.total()
+    }
+
+    /// the number of issues fetched so far, including any not yet iterated over
+    pub fn fetched_so_far(&self) -> u64 {
+        self.pages.fetched_so_far()
     }
 
-    fn more(&self) -> bool {
-        (self.results.start_at + self.results.max_results) <= self.results.total
+    /// whether the last page of issues has been fetched
+    pub fn is_last_page(&self) -> bool {
+        self.pages.is_last_page()
     }
 }
 
 impl<'a> Iterator for IssuesIter<'a> {
-    type Item = Issue;
-    fn next(&mut self) -> Option<Issue> {
-        self.results.values.pop().or_else(|| {
-            if self.more() {
-                match self.jira.issues().list(
-                    self.board,
-                    &self
-                        .search_options
-                        .as_builder()
-                        .max_results(self.results.max_results)
-                        .start_at(self.results.start_at + self.results.max_results)
-                        .build(),
-                ) {
-                    Ok(new_results) => {
-                        self.results = new_results;
-                        self.results.values.pop()
-                    }
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        })
+    type Item = Result<Issue>;
+    fn next(&mut self) -> Option<Result<Issue>> {
+        self.pages.next()
     }
 }
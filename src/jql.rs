@@ -0,0 +1,161 @@
+//! A builder for jql queries
+
+// Third party
+use std::fmt;
+
+/// the direction in which search results are ordered
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Asc,
+    Desc,
+}
+
+/// builds a jql query, quoting and escaping values so they are always matched literally.
+/// clauses are combined with `AND`, e.g.
+/// `Jql::new().project("ABC").status("In Progress").build()` builds
+/// `project = "ABC" AND status = "In Progress"`
+#[derive(Clone, Debug, Default)]
+pub struct Jql {
+    clauses: Vec<Clause>,
+    order_by: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+enum Clause {
+    /// a single comparison
+    Term(String),
+    /// an expression which must be parenthesized when combined with other clauses
+    Group(String),
+}
+
+impl Jql {
+    /// creates a new instance
+    pub fn new() -> Jql {
+        Jql::default()
+    }
+
+    /// creates an instance from a raw jql clause, included as is. useful for
+    /// operators and functions without a dedicated method, e.g. `assignee = currentUser()`
+    pub fn raw<R>(clause: R) -> Jql
+    where
+        R: Into<String>,
+    {
+        Jql {
+            clauses: vec![Clause::Group(clause.into())],
+            order_by: vec![],
+        }
+    }
+
+    /// matches issues whose `field` equals `value`
+    pub fn eq(&mut self, field: &str, value: &str) -> &mut Jql {
+        self.clauses
+            .push(Clause::Term(format!("{} = {}", field, quote(value))));
+        self
+    }
+
+    pub fn project(&mut self, key: &str) -> &mut Jql {
+        self.eq("project", key)
+    }
+
+    pub fn status(&mut self, status: &str) -> &mut Jql {
+        self.eq("status", status)
+    }
+
+    pub fn assignee(&mut self, assignee: &str) -> &mut Jql {
+        self.eq("assignee", assignee)
+    }
+
+    /// matches issues which also match the clauses of `other`
+    pub fn and(&mut self, other: &Jql) -> &mut Jql {
+        self.clauses.extend(other.clauses.iter().cloned());
+        self
+    }
+
+    /// matches issues which match either the clauses so far, or the clauses of `other`
+    pub fn or(&mut self, other: &Jql) -> &mut Jql {
+        if self.clauses.is_empty() {
+            self.clauses = other.clauses.clone();
+        } else if !other.clauses.is_empty() {
+            let either = format!("{} OR {}", self.operand(), other.operand());
+            self.clauses = vec![Clause::Group(either)];
+        }
+        self
+    }
+
+    /// orders results by `field`. may be called repeatedly to break ties
+    pub fn order_by(&mut self, field: &str, direction: Direction) -> &mut Jql {
+        let direction = match direction {
+            Direction::Asc => "ASC",
+            Direction::Desc => "DESC",
+        };
+        self.order_by.push(format!("{} {}", field, direction));
+        self
+    }
+
+    pub fn build(&self) -> String {
+        let mut query = self.condition().unwrap_or_default();
+        if !self.order_by.is_empty() {
+            if !query.is_empty() {
+                query.push(' ');
+            }
+            query.push_str("ORDER BY ");
+            query.push_str(&self.order_by.join(", "));
+        }
+        query
+    }
+
+    fn condition(&self) -> Option<String> {
+        match self.clauses.len() {
+            0 => None,
+            1 => match self.clauses[0] {
+                Clause::Term(ref clause) | Clause::Group(ref clause) => Some(clause.clone()),
+            },
+            _ => Some(
+                self.clauses
+                    .iter()
+                    .map(|clause| match *clause {
+                        Clause::Term(ref clause) => clause.clone(),
+                        Clause::Group(ref clause) => format!("({})", clause),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" AND "),
+            ),
+        }
+    }
+
+    /// the condition, parenthesized when it combines several clauses
+    fn operand(&self) -> String {
+        let condition = self.condition().unwrap_or_default();
+        match self.clauses.len() {
+            1 => condition,
+            _ => format!("({})", condition),
+        }
+    }
+}
+
+impl fmt::Display for Jql {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+/// quotes a value as a jql string literal, so that reserved words and
+/// special characters are not interpreted
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -1,34 +1,102 @@
 //! Goji provides an interface for Jira's REST api
 
+#[cfg(feature = "chrono")]
+extern crate chrono;
 #[macro_use]
 extern crate log;
+#[cfg(feature = "async")]
+extern crate futures;
 extern crate reqwest;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
+#[cfg(feature = "async")]
+extern crate tokio_timer;
+#[cfg(feature = "tracing")]
+extern crate tracing;
 extern crate url;
 
+use std::collections::HashMap;
+use std::fmt;
 use std::io::Read;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+#[cfg(feature = "tracing")]
+use std::time::Instant;
 
-use reqwest::header::CONTENT_TYPE;
-use reqwest::{Client, Method, StatusCode};
+use cache::ResponseCache;
+use reqwest::header::{HeaderMap, ACCEPT, CONTENT_TYPE, USER_AGENT};
+use retry::RetryPolicy;
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+pub mod adf;
+#[cfg(feature = "async")]
+pub mod async_client;
 mod builder;
+mod cache;
+#[cfg(feature = "chrono")]
+pub mod datetime;
+pub mod comments;
+pub mod components;
+pub mod dashboards;
+pub mod epics;
+pub mod filters;
+pub mod groups;
 mod errors;
 pub mod issues;
+mod jql;
+mod meta;
+mod pagination;
+pub mod projects;
+pub mod properties;
+pub mod remote_links;
 mod rep;
+mod retry;
 mod search;
+pub mod servicedesk;
 mod transitions;
+pub mod users;
+pub mod versions;
+pub mod votes;
+pub mod watchers;
+pub mod webhooks;
+pub mod worklogs;
 
+pub use adf::*;
+#[cfg(feature = "async")]
+pub use async_client::*;
 pub use builder::*;
+pub use comments::*;
+pub use components::*;
+pub use dashboards::*;
+pub use epics::*;
+pub use filters::*;
+pub use groups::*;
 pub use errors::*;
 pub use issues::*;
+pub use jql::*;
+pub use meta::*;
+pub use pagination::*;
+pub use projects::*;
+pub use properties::*;
+pub use remote_links::*;
 pub use rep::*;
+// response representations take precedence over the create-side
+// types of the same name, which remain available under `issues::`
+pub use rep::{IssueType, Priority, Project};
 pub use search::Search;
+pub use servicedesk::*;
 pub use transitions::*;
+pub use users::*;
+pub use versions::*;
+pub use votes::*;
+pub use watchers::*;
+pub use webhooks::*;
+pub use worklogs::*;
 pub mod boards;
 pub mod resolution;
 pub use boards::*;
@@ -41,10 +109,124 @@ pub struct EmptyResponse;
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Types of authentication credentials
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub enum Credentials {
     /// username and password credentials
-    Basic(String, String), // todo: OAuth
+    Basic(String, String),
+    /// an OAuth 2.0 access token, sent as a bearer token.
+    /// OAuth (3LO) apps address jira through the Atlassian api gateway,
+    /// see `Jira::cloud_gateway_host`
+    Bearer(String),
+    /// a personal access token for jira server or data center, sent as a bearer token.
+    /// unlike basic credentials, no username is needed
+    PersonalAccessToken(String),
+    /// a username and secret, sent as a bearer token to jira cloud hosts
+    /// (`*.atlassian.net` and `api.atlassian.com`) and as basic credentials to others
+    Auto(String, String),
+}
+
+impl Credentials {
+    /// the credentials sent to `host`, deciding how to send `Auto` credentials.
+    /// explicit `Basic` and `Bearer` credentials are always sent as given
+    pub fn resolve(&self, host: &str) -> Credentials {
+        match *self {
+            Credentials::Auto(ref user, ref secret) => {
+                if cloud_host(host) {
+                    Credentials::Bearer(secret.clone())
+                } else {
+                    Credentials::Basic(user.clone(), secret.clone())
+                }
+            }
+            ref explicit => explicit.clone(),
+        }
+    }
+}
+
+/// omits secrets, so that credentials may be safely logged
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Credentials::Basic(ref user, _) => {
+                f.debug_tuple("Basic").field(user).field(&"<redacted>").finish()
+            }
+            Credentials::Bearer(_) => f.debug_tuple("Bearer").field(&"<redacted>").finish(),
+            Credentials::PersonalAccessToken(_) => f
+                .debug_tuple("PersonalAccessToken")
+                .field(&"<redacted>")
+                .finish(),
+            Credentials::Auto(ref user, _) => {
+                f.debug_tuple("Auto").field(user).field(&"<redacted>").finish()
+            }
+        }
+    }
+}
+
+/// whether a host belongs to jira cloud, including the api gateway used by OAuth (3LO) apps
+fn cloud_host(host: &str) -> bool {
+    url::Url::parse(host)
+        .ok()
+        .and_then(|url| url.host_str().map(|name| name.to_ascii_lowercase()))
+        .is_some_and(|name| name.ends_with(".atlassian.net") || name == "api.atlassian.com")
+}
+
+/// versions of jira's core rest api, the `{version}` of `rest/api/{version}`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ApiVersion {
+    /// version 2, available on both jira server and cloud
+    V2,
+    /// version 3, jira cloud only. rich text fields are atlassian document format documents
+    V3,
+    /// whichever version the jira instance considers current
+    #[default]
+    Latest,
+}
+
+impl ApiVersion {
+    fn segment(self) -> &'static str {
+        match self {
+            ApiVersion::V2 => "2",
+            ApiVersion::V3 => "3",
+            ApiVersion::Latest => "latest",
+        }
+    }
+}
+
+/// kinds of jira installation, which differ in how they identify users
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Deployment {
+    /// jira server or data center, which identifies users by username
+    Server,
+    /// jira cloud, which identifies users by account id
+    Cloud,
+}
+
+impl Deployment {
+    /// guesses the deployment from a host, taking `*.atlassian.net` and `api.atlassian.com`
+    /// hosts to be jira cloud and any others to be jira server
+    pub fn detect(host: &str) -> Deployment {
+        if cloud_host(host) {
+            Deployment::Cloud
+        } else {
+            Deployment::Server
+        }
+    }
+
+    /// refers to a user the way this deployment identifies them, if the user carries
+    /// that identifier
+    pub fn assignee(self, user: &User) -> Option<Assignee> {
+        match self {
+            Deployment::Server => user.name.as_ref().map(Assignee::by_name),
+            Deployment::Cloud => user.account_id.as_ref().map(Assignee::by_account_id),
+        }
+    }
+
+    /// the query parameter user searches are matched against
+    fn user_query(self) -> &'static str {
+        match self {
+            Deployment::Server => "username",
+            Deployment::Cloud => "query",
+        }
+    }
 }
 
 /// Entrypoint into client interface
@@ -54,31 +236,268 @@ pub struct Jira {
     host: String,
     credentials: Credentials,
     client: Client,
+    retry: RetryPolicy,
+    api_version: ApiVersion,
+    deployment: Deployment,
+    user_agent: String,
+    cache: Option<Arc<ResponseCache>>,
 }
 
-impl Jira {
-    /// creates a new instance of a jira client
-    pub fn new<H>(host: H, credentials: Credentials) -> Result<Jira>
+/// the default bound on how long a request may take
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// identifies this client to jira administrators
+const DEFAULT_USER_AGENT: &str = concat!("jira-rs/", env!("CARGO_PKG_VERSION"));
+
+/// configures and creates a jira client
+#[derive(Clone, Debug)]
+pub struct JiraBuilder {
+    host: String,
+    credentials: Credentials,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    client: Option<Client>,
+    retry: RetryPolicy,
+    api_version: ApiVersion,
+    deployment: Option<Deployment>,
+    gzip: bool,
+    user_agent: String,
+    cache: Option<Duration>,
+}
+
+impl JiraBuilder {
+    /// creates a new instance. the host may carry the context path of a
+    /// jira server installation, e.g. `https://example.com/jira`
+    pub fn new<H>(host: H, credentials: Credentials) -> JiraBuilder
     where
         H: Into<String>,
     {
-        Ok(Jira {
+        JiraBuilder {
             host: host.into(),
-            client: Client::new(),
             credentials,
+            timeout: Some(DEFAULT_TIMEOUT),
+            connect_timeout: None,
+            client: None,
+            retry: RetryPolicy::default(),
+            api_version: ApiVersion::default(),
+            deployment: None,
+            gzip: true,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            cache: None,
+        }
+    }
+
+    /// bounds how long a request may take as a whole, 30 seconds by default.
+    /// requests exceeding it fail with `Error::Timeout`
+    pub fn timeout(&mut self, timeout: Duration) -> &mut JiraBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// bounds how long connecting to jira may take
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut JiraBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// retries requests which were rate limited (`429`), found jira unavailable (`503`) or
+    /// failed at a gateway (`502`, `504`) up to `retries` times, waiting as long as jira's
+    /// `Retry-After` header asks or backing off exponentially, see `retry_backoff`.
+    /// by default, no requests are retried
+    pub fn max_retries(&mut self, retries: u32) -> &mut JiraBuilder {
+        self.retry.max_retries = retries;
+        self
+    }
+
+    /// whether to ask jira for gzip compressed responses, which are decompressed before being
+    /// read. enabled by default. has no effect when a client is supplied with `client`
+    pub fn gzip(&mut self, enable: bool) -> &mut JiraBuilder {
+        self.gzip = enable;
+        self
+    }
+
+    /// the version of the core rest api requests are sent to. agile requests are unaffected.
+    /// defaults to `ApiVersion::Latest`
+    pub fn api_version(&mut self, version: ApiVersion) -> &mut JiraBuilder {
+        self.api_version = version;
+        self
+    }
+
+    /// the kind of jira installation, which decides how deployment aware methods like
+    /// `Users::find` look up users. by default, it is detected from the host
+    pub fn deployment(&mut self, deployment: Deployment) -> &mut JiraBuilder {
+        self.deployment = Some(deployment);
+        self
+    }
+
+    /// whether requests which failed at a gateway, with a `502` or `504`, are retried like
+    /// unavailable (`503`) ones, up to `max_retries` times. enabled by default
+    pub fn retry_server_errors(&mut self, retry: bool) -> &mut JiraBuilder {
+        self.retry.server_errors = retry;
+        self
+    }
+
+    /// bounds how long to wait between retries when jira doesn't say with a `Retry-After`
+    /// header. the wait starts at `base`, doubling for each retry up to `cap`, less a random
+    /// jitter of up to half. by default, 500 milliseconds up to 30 seconds
+    pub fn retry_backoff(&mut self, base: Duration, cap: Duration) -> &mut JiraBuilder {
+        self.retry.backoff_base = base;
+        self.retry.backoff_cap = cap;
+        self
+    }
+
+    /// whether requests which may not be safely repeated, like creating an issue with a `POST`,
+    /// are also retried. a rate limited `POST` may still have taken effect, so by default only
+    /// `GET`, `HEAD`, `PUT`, `DELETE` and `OPTIONS` requests are retried
+    pub fn retry_non_idempotent(&mut self, retry: bool) -> &mut JiraBuilder {
+        self.retry.non_idempotent = retry;
+        self
+    }
+
+    /// the `User-Agent` sent with every request, by default `jira-rs/<version>`
+    pub fn user_agent<U>(&mut self, user_agent: U) -> &mut JiraBuilder
+    where
+        U: Into<String>,
+    {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// keeps responses from endpoints serving rarely changing reference data, like
+    /// `priorities` and `create_meta`, for `ttl` before fetching them again. the cache is
+    /// shared by clones of the built client. by default, nothing is cached
+    pub fn cache(&mut self, ttl: Duration) -> &mut JiraBuilder {
+        self.cache = Some(ttl);
+        self
+    }
+
+    /// sends requests with a preconfigured reqwest client, e.g. one using a proxy or
+    /// custom tls roots, or one shared with other jira clients. the client's own timeouts
+    /// apply in place of `timeout` and `connect_timeout`
+    pub fn client(&mut self, client: Client) -> &mut JiraBuilder {
+        self.client = Some(client);
+        self
+    }
+
+    pub fn build(&self) -> Result<Jira> {
+        let client = match self.client {
+            Some(ref client) => client.clone(),
+            None => Client::builder()
+                .timeout(self.timeout)
+                .connect_timeout(self.connect_timeout)
+                .gzip(self.gzip)
+                .build()?,
+        };
+        let host = self.host.trim_end_matches('/').to_owned();
+        Ok(Jira {
+            deployment: self
+                .deployment
+                .unwrap_or_else(|| Deployment::detect(&host)),
+            host,
+            credentials: self.credentials.clone(),
+            client,
+            retry: self.retry.clone(),
+            api_version: self.api_version,
+            user_agent: self.user_agent.clone(),
+            cache: self.cache.map(|ttl| Arc::new(ResponseCache::new(ttl))),
         })
     }
+}
+
+impl Jira {
+    /// creates a new instance of a jira client
+    pub fn new<H>(host: H, credentials: Credentials) -> Result<Jira>
+    where
+        H: Into<String>,
+    {
+        JiraBuilder::new(host, credentials).build()
+    }
+
+    /// returns a builder for configuring a jira client
+    pub fn builder<H>(host: H, credentials: Credentials) -> JiraBuilder
+    where
+        H: Into<String>,
+    {
+        JiraBuilder::new(host, credentials)
+    }
 
     /// creates a new instance of a jira client using a specified reqwest client
     pub fn from_client<H>(host: H, credentials: Credentials, client: Client) -> Result<Jira>
     where
         H: Into<String>,
     {
-        Ok(Jira {
-            host: host.into(),
-            credentials,
-            client,
-        })
+        JiraBuilder::new(host, credentials).client(client).build()
+    }
+
+    /// returns the host through which OAuth (3LO) apps reach a jira cloud site,
+    /// identified by its cloud id
+    pub fn cloud_gateway_host<C>(cloud_id: C) -> String
+    where
+        C: Into<String>,
+    {
+        format!("https://api.atlassian.com/ex/jira/{}", cloud_id.into())
+    }
+
+    /// returns the version of the core rest api this client talks to
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// returns the kind of jira installation this client talks to
+    pub fn deployment(&self) -> Deployment {
+        self.deployment
+    }
+
+    /// returns the user whose credentials are in use
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/myself-getUser
+    pub fn myself(&self) -> Result<User> {
+        self.get("api", "/myself")
+    }
+
+    /// returns the named permissions, e.g. `CREATE_ISSUES`, and whether the current user holds
+    /// each of them. newer jira versions require at least one permission to be named
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/mypermissions-getPermissions
+    pub fn my_permissions(&self, keys: &[&str]) -> Result<HashMap<String, Permission>> {
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("permissions", &keys.join(","))
+            .finish();
+        self.get::<Permissions>("api", &format!("/mypermissions?{}", query))
+            .map(|permissions| permissions.permissions)
+    }
+
+    /// returns labels in use which start with `query`, for autocompletion
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/jql/autocompletedata-getFieldAutoCompleteForQueryString
+    pub fn label_suggestions(&self, query: &str) -> Result<Vec<String>> {
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("fieldName", "labels")
+            .append_pair("fieldValue", query)
+            .finish();
+        self.get::<Suggestions>("api", &format!("/jql/autocompletedata/suggestions?{}", query))
+            .map(|suggestions| {
+                suggestions
+                    .results
+                    .into_iter()
+                    .map(|suggestion| suggestion.value)
+                    .collect()
+            })
+    }
+
+    /// returns the issue types visible to the current user
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issuetype-getIssueAllTypes
+    pub fn issue_types(&self) -> Result<Vec<IssueType>> {
+        self.get_cached("api", "/issuetype")
+    }
+
+    /// returns the priorities issues may be given
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/priority-getPriorities
+    pub fn priorities(&self) -> Result<Vec<Priority>> {
+        self.get_cached("api", "/priority")
+    }
+
+    /// returns the statuses of every workflow
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/status-getStatuses
+    pub fn statuses(&self) -> Result<Vec<Status>> {
+        self.get_cached("api", "/status")
     }
 
     /// return transitions interface
@@ -99,6 +518,41 @@ impl Jira {
         Issues::new(self)
     }
 
+    // return projects interface
+    pub fn projects(&self) -> Projects {
+        Projects::new(self)
+    }
+
+    // return users interface
+    pub fn users(&self) -> Users {
+        Users::new(self)
+    }
+
+    // return components interface
+    pub fn components(&self) -> Components {
+        Components::new(self)
+    }
+
+    // return dashboards interface
+    pub fn dashboards(&self) -> Dashboards {
+        Dashboards::new(self)
+    }
+
+    // return filters interface
+    pub fn filters(&self) -> Filters {
+        Filters::new(self)
+    }
+
+    // return groups interface
+    pub fn groups(&self) -> Groups {
+        Groups::new(self)
+    }
+
+    // return versions interface
+    pub fn versions(&self) -> Versions {
+        Versions::new(self)
+    }
+
     // return boards interface
     pub fn boards(&self) -> Boards {
         Boards::new(self)
@@ -109,6 +563,21 @@ impl Jira {
         Sprints::new(self)
     }
 
+    // return service desk interface
+    pub fn service_desk(&self) -> ServiceDesk {
+        ServiceDesk::new(self)
+    }
+
+    // return webhooks interface
+    pub fn webhooks(&self) -> Webhooks {
+        Webhooks::new(self)
+    }
+
+    // return epics interface
+    pub fn epics(&self) -> Epics {
+        Epics::new(self)
+    }
+
     pub fn post<D, S>(&self, api_name: &str, endpoint: &str, body: S) -> Result<D>
     where
         D: DeserializeOwned,
@@ -129,6 +598,23 @@ impl Jira {
         self.request::<D>(Method::PUT, api_name, endpoint, Some(data.into_bytes()))
     }
 
+    pub fn patch<D, S>(&self, api_name: &str, endpoint: &str, body: S) -> Result<D>
+    where
+        D: DeserializeOwned,
+        S: Serialize,
+    {
+        let data = serde_json::to_string::<S>(&body)?;
+        debug!("Json request: {}", data);
+        self.request::<D>(Method::PATCH, api_name, endpoint, Some(data.into_bytes()))
+    }
+
+    pub fn delete<D>(&self, api_name: &str, endpoint: &str) -> Result<D>
+    where
+        D: DeserializeOwned,
+    {
+        self.request::<D>(Method::DELETE, api_name, endpoint, None)
+    }
+
     pub fn get<D>(&self, api_name: &str, endpoint: &str) -> Result<D>
     where
         D: DeserializeOwned,
@@ -136,6 +622,49 @@ impl Jira {
         self.request::<D>(Method::GET, api_name, endpoint, None)
     }
 
+    /// sends a `GET` to any endpoint, returning the response as untyped json. useful for
+    /// reaching endpoints which have no typed interface here
+    pub fn get_json(&self, api_name: &str, endpoint: &str) -> Result<serde_json::Value> {
+        self.get(api_name, endpoint)
+    }
+
+    /// sends a `POST` with a json body to any endpoint, returning the response as untyped json
+    pub fn post_json(
+        &self,
+        api_name: &str,
+        endpoint: &str,
+        body: &serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        self.post(api_name, endpoint, body)
+    }
+
+    /// like `get`, but answered from the response cache while it holds a fresh response
+    pub(crate) fn get_cached<D>(&self, api_name: &str, endpoint: &str) -> Result<D>
+    where
+        D: DeserializeOwned,
+    {
+        let cache = match self.cache {
+            Some(ref cache) => cache,
+            None => return self.get(api_name, endpoint),
+        };
+        let url = self.url(api_name, endpoint);
+        if let Some(body) = cache.get(&url) {
+            debug!("Cached response for {}", url);
+            // only json responses are cached
+            return respond(StatusCode::OK, None, &body);
+        }
+
+        let mut res = self.send(Method::GET, &url, |req| req)?;
+        let content_type = content_type(res.headers());
+        let mut body = String::new();
+        res.read_to_string(&mut body)?;
+        #[cfg(feature = "tracing")]
+        tracing::trace!(body = %body, "jira response body");
+        let value = respond(res.status(), content_type.as_deref(), &body)?;
+        cache.insert(&url, body);
+        Ok(value)
+    }
+
     pub fn request<D>(
         &self,
         method: Method,
@@ -146,36 +675,200 @@ impl Jira {
     where
         D: DeserializeOwned,
     {
-        let url = format!("{}/rest/{}/latest{}", self.host, api_name, endpoint);
+        let url = self.url(api_name, endpoint);
         //println!("url -> {:?}", url);
 
-        let req = self.client.request(method, &url);
-        let builder = match self.credentials {
-            Credentials::Basic(ref user, ref pass) => req
-                .basic_auth(user.to_owned(), Some(pass.to_owned()))
-                .header(CONTENT_TYPE, "application/json"),
-        };
+        #[cfg(feature = "tracing")]
+        {
+            if let Some(ref bod) = body {
+                tracing::trace!(body = %String::from_utf8_lossy(bod), "jira request body");
+            }
+        }
 
-        let mut res = match body {
-            Some(bod) => builder.body(bod).send()?,
-            _ => builder.send()?,
-        };
+        let res = self.send(method, &url, |req| {
+            let req = req.header(CONTENT_TYPE, "application/json");
+            match body {
+                Some(ref bod) => req.body(bod.clone()),
+                _ => req,
+            }
+        })?;
+        read(res)
+    }
 
-        let mut body = String::new();
-        res.read_to_string(&mut body)?;
-        //println!("status {:?} body '{:?}'", res.status(), body);
-        match res.status() {
-            StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
-            StatusCode::METHOD_NOT_ALLOWED => Err(Error::MethodNotAllowed),
-            StatusCode::NOT_FOUND => Err(Error::NotFound),
-            client_err if client_err.is_client_error() => Err(Error::Fault {
-                code: res.status(),
-                errors: serde_json::from_str::<Errors>(&body)?,
-            }),
-            _ => {
-                let data = if body == "" { "null" } else { &body };
-                Ok(serde_json::from_str::<D>(data)?)
+    /// sends an authenticated request for json, retrying it as configured. `prepare` is
+    /// applied to each attempt, so must be able to rebuild the request's body
+    pub(crate) fn send<F>(&self, method: Method, url: &str, prepare: F) -> Result<Response>
+    where
+        F: Fn(RequestBuilder) -> RequestBuilder,
+    {
+        self.send_accepting(method, url, "application/json", prepare)
+    }
+
+    /// like `send`, but accepting responses of the media types in `accept`, e.g. `*/*` for
+    /// attachment content
+    pub(crate) fn send_accepting<F>(
+        &self,
+        method: Method,
+        url: &str,
+        accept: &str,
+        prepare: F,
+    ) -> Result<Response>
+    where
+        F: Fn(RequestBuilder) -> RequestBuilder,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("jira", method = %method, url = %redact(url)).entered();
+
+        let mut attempt = 0;
+        loop {
+            #[cfg(feature = "tracing")]
+            let started = Instant::now();
+            let req = self
+                .client
+                .request(method.clone(), url)
+                .header(USER_AGENT, self.user_agent.as_str())
+                .header(ACCEPT, accept);
+            // resolved credentials are never `Auto`
+            let builder = match self.credentials.resolve(&self.host) {
+                Credentials::Basic(user, pass) | Credentials::Auto(user, pass) => {
+                    req.basic_auth(user, Some(pass))
+                }
+                Credentials::Bearer(token) | Credentials::PersonalAccessToken(token) => {
+                    req.bearer_auth(token)
+                }
+            };
+
+            let res = prepare(builder).send()?;
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                status = res.status().as_u16(),
+                elapsed_ms = started.elapsed().as_millis() as u64,
+                "jira response"
+            );
+
+            if !self.retry.retries(&method, res.status(), attempt) {
+                return Ok(res);
             }
+            let delay = self.retry.delay(res.headers(), attempt);
+            debug!("Retrying {} {} in {:?}", method, url, delay);
+            thread::sleep(delay);
+            attempt += 1;
         }
     }
+
+    pub(crate) fn url(&self, api_name: &str, endpoint: &str) -> String {
+        rest_url(&self.host, self.api_version, api_name, endpoint)
+    }
+}
+
+/// the url of an endpoint of one of jira's rest apis, e.g. `api` or `agile`
+fn rest_url(host: &str, api_version: ApiVersion, api_name: &str, endpoint: &str) -> String {
+    match api_name {
+        "api" => format!("{}/rest/api/{}{}", host, api_version.segment(), endpoint),
+        // the service desk api is unversioned
+        "servicedeskapi" => format!("{}/rest/servicedeskapi{}", host, endpoint),
+        _ => format!("{}/rest/{}/latest{}", host, api_name, endpoint),
+    }
+}
+
+/// reads and interprets a response from jira
+fn read<D>(mut res: Response) -> Result<D>
+where
+    D: DeserializeOwned,
+{
+    let content_type = content_type(res.headers());
+    let mut body = String::new();
+    res.read_to_string(&mut body)?;
+    //println!("status {:?} body '{:?}'", res.status(), body);
+    #[cfg(feature = "tracing")]
+    tracing::trace!(body = %body, "jira response body");
+    respond(res.status(), content_type.as_deref(), &body)
+}
+
+/// the content type of a response, if it declared one
+fn content_type(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_owned())
+}
+
+/// strips any credentials embedded in a url, so that it may be safely recorded
+#[cfg(feature = "tracing")]
+fn redact(url: &str) -> String {
+    match url::Url::parse(url) {
+        Ok(mut parsed) => {
+            let _ = parsed.set_username("");
+            let _ = parsed.set_password(None);
+            parsed.to_string()
+        }
+        Err(_) => "<unparseable url>".to_owned(),
+    }
+}
+
+/// the most of an unexpected response body kept for diagnostics, in characters
+const BODY_SNIPPET_LEN: usize = 200;
+
+/// interprets a response from jira, deserializing successful response bodies. a successful
+/// response which declares a content type other than json is an error
+fn respond<D>(status: StatusCode, content_type: Option<&str>, body: &str) -> Result<D>
+where
+    D: DeserializeOwned,
+{
+    match status {
+        StatusCode::UNAUTHORIZED => Err(Error::Unauthorized {
+            errors: reported_errors(body),
+        }),
+        StatusCode::FORBIDDEN => Err(Error::Forbidden {
+            errors: reported_errors(body),
+        }),
+        StatusCode::METHOD_NOT_ALLOWED => Err(Error::MethodNotAllowed {
+            errors: reported_errors(body),
+        }),
+        StatusCode::NOT_FOUND => Err(Error::NotFound {
+            errors: reported_errors(body),
+        }),
+        status if !status.is_success() => Err(Error::Fault {
+            code: status,
+            errors: reported_errors(body),
+        }),
+        _ => {
+            match content_type {
+                Some(content_type) if !body.trim().is_empty() && !is_json(content_type) => {
+                    return Err(Error::UnexpectedContentType {
+                        content_type: content_type.to_owned(),
+                        status,
+                        body_snippet: body.trim().chars().take(BODY_SNIPPET_LEN).collect(),
+                    })
+                }
+                _ => (),
+            }
+            // no content, e.g. a 204, reads as null, so as `()`, `None` or `EmptyResponse`
+            let data = if body.trim().is_empty() { "null" } else { body };
+            Ok(serde_json::from_str::<D>(data)?)
+        }
+    }
+}
+
+/// whether a content type is json, e.g. `application/json;charset=UTF-8`
+fn is_json(content_type: &str) -> bool {
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    media_type == "application/json" || media_type.ends_with("+json")
+}
+
+/// the messages jira reported in the body of an unsuccessful response
+fn reported_errors(body: &str) -> Errors {
+    serde_json::from_str::<Errors>(body).unwrap_or_else(|_| {
+        // not every failure comes with a json body, e.g. gateway errors
+        let mut errors = Errors::default();
+        if !body.trim().is_empty() {
+            errors.error_messages.push(body.trim().to_owned());
+        }
+        errors
+    })
 }
@@ -0,0 +1,74 @@
+// Third party
+use std::collections::BTreeMap;
+
+/// describes what is needed to create issues in a set of projects
+#[derive(Deserialize, Debug)]
+pub struct CreateMeta {
+    pub expand: Option<String>,
+    pub projects: Vec<CreateMetaProject>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateMetaProject {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    pub issuetypes: Vec<CreateMetaIssueType>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateMetaIssueType {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub subtask: bool,
+    /// field definitions, keyed by field id
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldMeta>,
+}
+
+/// describes the fields which may be edited on an issue in its current state
+#[derive(Deserialize, Debug)]
+pub struct EditMeta {
+    /// field definitions, keyed by field id
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldMeta>,
+}
+
+/// describes a field which may be set on an issue
+#[derive(Deserialize, Debug)]
+pub struct FieldMeta {
+    pub required: bool,
+    pub schema: FieldSchema,
+    pub name: String,
+    pub key: Option<String>,
+    #[serde(rename = "hasDefaultValue")]
+    pub has_default_value: Option<bool>,
+    /// the operations, e.g. `set` or `add`, the field supports
+    #[serde(default)]
+    pub operations: Vec<String>,
+    /// values the field is restricted to, for fields like priority or components
+    #[serde(rename = "allowedValues", default)]
+    pub allowed_values: Vec<::serde_json::Value>,
+    #[serde(rename = "autoCompleteUrl")]
+    pub auto_complete_url: Option<String>,
+}
+
+/// describes the type of value a field holds
+#[derive(Deserialize, Debug)]
+pub struct FieldSchema {
+    #[serde(rename = "type")]
+    pub schema_type: String,
+    /// the type of array elements, for array fields
+    pub items: Option<String>,
+    /// the name of a system field
+    pub system: Option<String>,
+    /// the type key of a custom field
+    pub custom: Option<String>,
+    #[serde(rename = "customId")]
+    pub custom_id: Option<u64>,
+}
@@ -0,0 +1,149 @@
+//! Iteration over paginated results
+
+// Third party
+use std::fmt;
+
+// Ours
+use {Paginated, Result, SearchOptions};
+
+/// the closure the iterators wrapping a `PageIter` fetch their pages with
+pub(crate) type Fetch<'a, T> = Box<dyn Fn(&SearchOptions) -> Result<Paginated<T>> + 'a>;
+
+/// provides an iterator over consecutive pages of results, fetched with `fetch`.
+/// no page is fetched until the iterator is first advanced, and a failed fetch is
+/// yielded as an error which ends the iteration
+pub struct PageIter<T, F> {
+    fetch: F,
+    search_options: SearchOptions,
+    /// the current page, `None` until the first page is fetched
+    results: Option<Paginated<T>>,
+    /// offset of the first value following the current page
+    next_start_at: u64,
+    /// values fetched across every page so far
+    fetched: u64,
+    failed: bool,
+}
+
+impl<T, F> PageIter<T, F>
+where
+    F: Fn(&SearchOptions) -> Result<Paginated<T>>,
+{
+    pub fn new(options: &SearchOptions, fetch: F) -> Self {
+        PageIter {
+            fetch,
+            search_options: options.clone(),
+            results: None,
+            next_start_at: 0,
+            fetched: 0,
+            failed: false,
+        }
+    }
+
+    /// the total number of results jira reported with the last page fetched, or 0 before
+    /// the first page is fetched. agile endpoints which report `is_last` in place of a total
+    /// always report 0
+    pub fn total(&self) -> u64 {
+        self.results.as_ref().map_or(0, |results| results.total)
+    }
+
+    /// the number of results fetched so far, including any not yet iterated over
+    pub fn fetched_so_far(&self) -> u64 {
+        self.fetched
+    }
+
+    /// whether the last page has been fetched, so no further requests will be made
+    pub fn is_last_page(&self) -> bool {
+        self.results.is_some() && !self.more()
+    }
+
+    /// records paging state for a freshly fetched page
+    fn loaded(&mut self, mut results: Paginated<T>) {
+        self.next_start_at = results.start_at + results.values.len() as u64;
+        self.fetched += results.values.len() as u64;
+        // pages are consumed from the back, so keep them in reverse server order
+        results.values.reverse();
+        self.results = Some(results);
+    }
+
+    fn more(&self) -> bool {
+        if self.failed {
+            return false;
+        }
+        let results = match self.results {
+            Some(ref results) => results,
+            None => return true,
+        };
+        // an empty page means the server has nothing further to offer
+        if self.next_start_at <= results.start_at {
+            return false;
+        }
+        match results.is_last {
+            Some(is_last) => !is_last,
+            None => self.next_start_at < results.total,
+        }
+    }
+
+    fn next_options(&self) -> SearchOptions {
+        match self.results {
+            Some(ref results) => {
+                // keep to the requested page size, but never ask for more than jira allows
+                let max_results = match self.search_options.max_results() {
+                    Some(requested) => requested.min(results.max_results),
+                    None => results.max_results,
+                };
+                self.search_options
+                    .as_builder()
+                    .max_results(max_results)
+                    .start_at(self.next_start_at)
+                    .build()
+            }
+            None => self.search_options.clone(),
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.results
+            .as_mut()
+            .and_then(|results| results.values.pop())
+    }
+}
+
+impl<T, F> Iterator for PageIter<T, F>
+where
+    F: Fn(&SearchOptions) -> Result<Paginated<T>>,
+{
+    type Item = Result<T>;
+    fn next(&mut self) -> Option<Result<T>> {
+        if let Some(value) = self.pop() {
+            return Some(Ok(value));
+        }
+        if !self.more() {
+            return None;
+        }
+        match (self.fetch)(&self.next_options()) {
+            Ok(results) => {
+                self.loaded(results);
+                self.pop().map(Ok)
+            }
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<T, F> fmt::Debug for PageIter<T, F>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PageIter")
+            .field("search_options", &self.search_options)
+            .field("results", &self.results)
+            .field("next_start_at", &self.next_start_at)
+            .field("fetched", &self.fetched)
+            .field("failed", &self.failed)
+            .finish()
+    }
+}
@@ -0,0 +1,135 @@
+//! Interfaces for accessing projects
+
+// Third party
+use std::collections::HashMap;
+
+// Ours
+use {Component, Jira, Project, Result, Status, Version};
+
+/// project options
+#[derive(Debug)]
+pub struct Projects {
+    jira: Jira,
+}
+
+/// a role within a project, along with the users and groups which hold it
+#[derive(Deserialize, Debug)]
+pub struct ProjectRole {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub id: u64,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub actors: Vec<RoleActor>,
+}
+
+/// a user or group holding a project role
+#[derive(Deserialize, Debug)]
+pub struct RoleActor {
+    pub id: u64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    /// `atlassian-user-role-actor` for users, `atlassian-group-role-actor` for groups
+    #[serde(rename = "type")]
+    pub actor_type: String,
+    /// the username or group name of the actor
+    pub name: Option<String>,
+    #[serde(rename = "avatarUrl")]
+    pub avatar_url: Option<String>,
+}
+
+impl RoleActor {
+    /// whether the actor is a group, rather than a single user
+    pub fn is_group(&self) -> bool {
+        self.actor_type == "atlassian-group-role-actor"
+    }
+}
+
+/// an issue type of a project, along with the statuses its workflow in that project uses
+#[derive(Deserialize, Debug)]
+pub struct ProjectIssueTypeStatuses {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub subtask: bool,
+    #[serde(default)]
+    pub statuses: Vec<Status>,
+}
+
+/// users and groups to add to a project role
+#[derive(Serialize, Debug, Default)]
+pub struct AddRoleActors {
+    /// usernames, or account ids on jira cloud
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub user: Vec<String>,
+    /// group names
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub group: Vec<String>,
+}
+
+impl Projects {
+    pub fn new(jira: &Jira) -> Projects {
+        Projects { jira: jira.clone() }
+    }
+
+    /// returns a single project by key or id
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getProject
+    pub fn get<K>(&self, key: K) -> Result<Project>
+    where
+        K: Into<String>,
+    {
+        self.jira.get("api", &format!("/project/{}", key.into()))
+    }
+
+    /// returns all projects visible to the current user
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getAllProjects
+    pub fn list(&self) -> Result<Vec<Project>> {
+        self.jira.get("api", "/project")
+    }
+
+    /// returns the versions of a project
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getProjectVersions
+    pub fn versions(&self, key: &str) -> Result<Vec<Version>> {
+        self.jira.get("api", &format!("/project/{}/versions", key))
+    }
+
+    /// returns the components of a project
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getProjectComponents
+    pub fn components(&self, key: &str) -> Result<Vec<Component>> {
+        self.jira.get("api", &format!("/project/{}/components", key))
+    }
+
+    /// returns the issue types of a project, each with the statuses of its workflow
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project-getAllStatuses
+    pub fn statuses(&self, key: &str) -> Result<Vec<ProjectIssueTypeStatuses>> {
+        self.jira.get("api", &format!("/project/{}/statuses", key))
+    }
+
+    /// returns the roles of a project, as a map of role names to the urls of the roles
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project/{projectIdOrKey}/role-getProjectRoles
+    pub fn roles(&self, key: &str) -> Result<HashMap<String, String>> {
+        self.jira.get("api", &format!("/project/{}/role", key))
+    }
+
+    /// returns a role of a project, along with its actors
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project/{projectIdOrKey}/role-getProjectRole
+    pub fn role(&self, key: &str, role_id: u64) -> Result<ProjectRole> {
+        self.jira
+            .get("api", &format!("/project/{}/role/{}", key, role_id))
+    }
+
+    /// adds users or groups to a role of a project
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/project/{projectIdOrKey}/role-addActorUsers
+    pub fn add_role_actors(
+        &self,
+        key: &str,
+        role_id: u64,
+        actors: AddRoleActors,
+    ) -> Result<ProjectRole> {
+        self.jira
+            .post("api", &format!("/project/{}/role/{}", key, role_id), actors)
+    }
+}
@@ -0,0 +1,76 @@
+//! Interfaces for accessing and managing issue properties
+
+// Third party
+use serde_json::Value;
+
+// Ours
+use {Jira, Result};
+
+/// interface for a single property stored on an issue
+#[derive(Debug)]
+pub struct IssueProperty {
+    jira: Jira,
+    issue: String,
+    key: String,
+}
+
+#[derive(Deserialize)]
+struct Property {
+    value: Value,
+}
+
+#[derive(Deserialize)]
+struct PropertyKeys {
+    keys: Vec<PropertyKey>,
+}
+
+#[derive(Deserialize)]
+struct PropertyKey {
+    key: String,
+}
+
+impl IssueProperty {
+    pub fn new<I, K>(jira: &Jira, issue: I, key: K) -> IssueProperty
+    where
+        I: Into<String>,
+        K: Into<String>,
+    {
+        IssueProperty {
+            jira: jira.clone(),
+            issue: issue.into(),
+            key: key.into(),
+        }
+    }
+
+    fn endpoint(&self) -> String {
+        format!("/issue/{}/properties/{}", self.issue, self.key)
+    }
+
+    /// returns the value stored under this property
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue/{issueIdOrKey}/properties-getProperty
+    pub fn get(&self) -> Result<Value> {
+        self.jira
+            .get::<Property>("api", &self.endpoint())
+            .map(|property| property.value)
+    }
+
+    /// stores a value under this property, replacing any previous value
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue/{issueIdOrKey}/properties-setProperty
+    pub fn set(&self, value: &Value) -> Result<()> {
+        self.jira.put("api", &self.endpoint(), value)
+    }
+
+    /// removes this property from the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue/{issueIdOrKey}/properties-deleteProperty
+    pub fn delete(&self) -> Result<()> {
+        self.jira.delete("api", &self.endpoint())
+    }
+
+    /// returns the keys of all properties stored on the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue/{issueIdOrKey}/properties-getPropertiesKeys
+    pub fn keys(&self) -> Result<Vec<String>> {
+        self.jira
+            .get::<PropertyKeys>("api", &format!("/issue/{}/properties", self.issue))
+            .map(|keys| keys.keys.into_iter().map(|key| key.key).collect())
+    }
+}
@@ -0,0 +1,105 @@
+//! Interfaces for accessing and managing links from issues to external resources
+
+// Ours
+use {Jira, Result};
+
+/// remote links interface for a single issue
+#[derive(Debug)]
+pub struct RemoteLinks {
+    jira: Jira,
+    key: String,
+}
+
+/// represents a link from an issue to an external resource
+#[derive(Deserialize, Debug)]
+pub struct RemoteLink {
+    pub id: u64,
+    #[serde(rename = "self")]
+    pub self_link: String,
+    #[serde(rename = "globalId")]
+    pub global_id: Option<String>,
+    pub relationship: Option<String>,
+    pub object: RemoteObject,
+}
+
+/// the external resource a remote link refers to
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RemoteObject {
+    pub url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+/// a new remote link. links are identified by their `global_id`, when one is given,
+/// so creating a link with the global id of an existing link updates that link in place
+#[derive(Serialize, Debug)]
+pub struct CreateRemoteLink {
+    #[serde(rename = "globalId", skip_serializing_if = "Option::is_none")]
+    pub global_id: Option<String>,
+    /// describes how the issue relates to the resource, e.g. `causes`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<String>,
+    pub object: RemoteObject,
+}
+
+impl CreateRemoteLink {
+    pub fn new<U, T>(url: U, title: T) -> CreateRemoteLink
+    where
+        U: Into<String>,
+        T: Into<String>,
+    {
+        CreateRemoteLink {
+            global_id: None,
+            relationship: None,
+            object: RemoteObject {
+                url: url.into(),
+                title: title.into(),
+                summary: None,
+            },
+        }
+    }
+}
+
+/// identifies a created or updated remote link
+#[derive(Deserialize, Debug)]
+pub struct RemoteLinkId {
+    pub id: u64,
+    #[serde(rename = "self")]
+    pub self_link: String,
+}
+
+impl RemoteLinks {
+    pub fn new<K>(jira: &Jira, key: K) -> RemoteLinks
+    where
+        K: Into<String>,
+    {
+        RemoteLinks {
+            jira: jira.clone(),
+            key: key.into(),
+        }
+    }
+
+    /// returns the remote links of the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getRemoteIssueLinks
+    pub fn list(&self) -> Result<Vec<RemoteLink>> {
+        self.jira
+            .get("api", &format!("/issue/{}/remotelink", self.key))
+    }
+
+    /// links the issue to an external resource, or updates the link with the same global id
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-createOrUpdateRemoteIssueLink
+    pub fn create(&self, link: CreateRemoteLink) -> Result<RemoteLinkId> {
+        self.jira
+            .post("api", &format!("/issue/{}/remotelink", self.key), link)
+    }
+
+    /// removes a remote link from the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-deleteRemoteIssueLinkById
+    pub fn delete(&self, link_id: u64) -> Result<()> {
+        self.jira.delete(
+            "api",
+            &format!("/issue/{}/remotelink/{}", self.key, link_id),
+        )
+    }
+}
@@ -1,18 +1,24 @@
 // Third party
-
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, FixedOffset};
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 // Ours
-use {Jira, Result};
+#[cfg(feature = "chrono")]
+use datetime;
+use {CommentBody, IssueRef, Jira, Paginated, Result};
 
 /// represents an general jira error response
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Default)]
 pub struct Errors {
-    #[serde(rename = "errorMessages")]
+    /// messages not associated with a particular field
+    #[serde(rename = "errorMessages", default)]
     pub error_messages: Vec<String>,
+    /// messages keyed by the name of the field they concern
+    #[serde(default)]
     pub errors: BTreeMap<String, String>,
 }
 
@@ -25,6 +31,13 @@ pub struct Issue {
     pub id: String,
     pub fields: BTreeMap<String, ::serde_json::Value>,
     pub changelog: Option<Changelog>,
+    /// html renderings of the issue's fields, only included when `renderedFields` is expanded
+    #[serde(rename = "renderedFields")]
+    pub rendered_fields: Option<RenderedFields>,
+    /// issue properties, keyed by property key, only included when requested with the
+    /// `properties` search option
+    #[serde(default)]
+    pub properties: BTreeMap<String, ::serde_json::Value>,
 }
 
 impl Issue {
@@ -38,6 +51,16 @@ impl Issue {
             .map(|value| Ok(serde_json::value::from_value::<F>(value.clone())?))
     }
 
+    /// resolves a typed issue property, included with the `properties` search option
+    pub fn property<P>(&self, key: &str) -> Option<Result<P>>
+    where
+        for<'de> P: Deserialize<'de>,
+    {
+        self.properties
+            .get(key)
+            .map(|value| Ok(serde_json::value::from_value::<P>(value.clone())?))
+    }
+
     fn user_field(&self, name: &str) -> Option<Result<User>> {
         self.field::<User>(name)
     }
@@ -87,6 +110,18 @@ impl Issue {
         self.string_field("created").and_then(|value| value.ok())
     }
 
+    /// updated timestamp, parsed
+    #[cfg(feature = "chrono")]
+    pub fn updated_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.updated().and_then(|value| datetime::parse(&value))
+    }
+
+    /// created timestamp, parsed
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created().and_then(|value| datetime::parse(&value))
+    }
+
     pub fn resolution_date(&self) -> Option<String> {
         self.string_field("resolutiondate")
             .and_then(|value| value.ok())
@@ -118,6 +153,13 @@ impl Issue {
             .and_then(|value| value.ok())
     }
 
+    /// the issue's subtasks
+    pub fn subtasks(&self) -> Vec<IssueRef> {
+        self.field::<Vec<IssueRef>>("subtasks")
+            .and_then(|value| value.ok())
+            .unwrap_or_default()
+    }
+
     /// links to other issues
     pub fn links(&self) -> Option<Result<Vec<IssueLink>>> {
         self.field::<Vec<IssueLink>>("issuelinks") //.and_then(|value| value.ok()).unwrap_or(vec![])
@@ -140,9 +182,9 @@ impl Issue {
     }
 
     pub fn comment(&self) -> Vec<Comment> {
-        self.field::<Comments>("comment")
+        self.field::<Paginated<Comment>>("comment")
             .and_then(|value| value.ok())
-            .map(|value| value.comments)
+            .map(|value| value.values)
             .unwrap_or_default()
     }
 
@@ -166,9 +208,11 @@ pub struct Attachment {
     pub thumbnail: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Comments {
-    pub comments: Vec<Comment>,
+impl Attachment {
+    /// downloads the content of this attachment
+    pub fn download(&self, jira: &Jira) -> Result<Vec<u8>> {
+        jira.issues().download_attachment(&self.content)
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -179,31 +223,90 @@ pub struct Comment {
     pub author: Option<User>,
     #[serde(rename = "updateAuthor")]
     pub update_author: Option<User>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(deserialize_with = "datetime::deserialize_checked")
+    )]
     pub created: String,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(deserialize_with = "datetime::deserialize_checked")
+    )]
     pub updated: String,
-    pub body: String,
+    pub body: CommentBody,
     pub visibility: Option<Visibility>,
 }
 
-#[derive(Deserialize, Debug)]
+#[cfg(feature = "chrono")]
+impl Comment {
+    /// created timestamp, parsed
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        datetime::parse(&self.created)
+    }
+
+    /// updated timestamp, parsed
+    pub fn updated_at(&self) -> Option<DateTime<FixedOffset>> {
+        datetime::parse(&self.updated)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
 pub struct Visibility {
     #[serde(rename = "type")]
     pub visibility_type: String,
     pub value: String,
 }
 
+/// an issue's text fields rendered as html
+#[derive(Deserialize, Debug)]
+pub struct RenderedFields {
+    pub description: Option<String>,
+    pub environment: Option<String>,
+    pub comment: Option<RenderedComments>,
+    /// any other rendered fields, e.g. text custom fields
+    #[serde(flatten)]
+    pub other: BTreeMap<String, ::serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RenderedComments {
+    #[serde(default)]
+    pub comments: Vec<RenderedComment>,
+}
+
+/// a comment whose body is rendered as html
+#[derive(Deserialize, Debug)]
+pub struct RenderedComment {
+    pub id: Option<String>,
+    pub body: String,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Changelog {
     pub histories: Vec<History>,
 }
 
+/// a set of changes made to an issue at once
 #[derive(Deserialize, Debug)]
 pub struct History {
+    pub id: Option<String>,
     pub author: User,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(deserialize_with = "datetime::deserialize_checked")
+    )]
     pub created: String,
     pub items: Vec<HistoryItem>,
 }
 
+#[cfg(feature = "chrono")]
+impl History {
+    /// created timestamp, parsed
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        datetime::parse(&self.created)
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct HistoryItem {
     pub field: String,
@@ -220,6 +323,11 @@ pub struct Project {
     pub id: String,
     pub key: String,
     pub name: String,
+    #[serde(rename = "projectTypeKey")]
+    pub project_type_key: Option<String>,
+    /// only included when fetching a single project
+    pub lead: Option<User>,
+    pub description: Option<String>,
 }
 
 /// represents link relationship between issues
@@ -247,33 +355,90 @@ pub struct LinkType {
     pub self_link: String,
 }
 
-#[derive(Deserialize, Debug)]
+/// a project version. versions are referred to by id alone when set on an issue
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Version {
+    #[serde(default, skip_serializing)]
     pub archived: bool,
+    #[serde(default, skip_serializing)]
+    pub description: Option<String>,
     pub id: String,
+    #[serde(default, skip_serializing)]
     pub name: String,
+    #[serde(rename = "releaseDate", default, skip_serializing)]
+    pub release_date: Option<String>,
+    #[serde(default, skip_serializing)]
     pub released: bool,
-    #[serde(rename = "self")]
+    #[serde(rename = "self", default, skip_serializing)]
     pub self_link: String,
 }
 
+impl Version {
+    /// refers to a version by id, e.g. to set it as an issue's fix version
+    pub fn new<I>(id: I) -> Version
+    where
+        I: Into<String>,
+    {
+        Version {
+            id: id.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// a jira user. server instances identify users by `name`,
+/// cloud instances by `account_id`
 #[derive(Deserialize, Debug)]
 pub struct User {
+    #[serde(rename = "accountId")]
+    pub account_id: Option<String>,
     pub active: bool,
-    #[serde(rename = "avatarUrls")]
+    #[serde(rename = "avatarUrls", default)]
     pub avatar_urls: BTreeMap<String, String>,
     #[serde(rename = "displayName")]
     pub display_name: String,
+    /// may be hidden depending on the user's privacy settings
     #[serde(rename = "emailAddress")]
-    pub email_address: String,
+    pub email_address: Option<String>,
     pub key: Option<String>,
-    pub name: String,
+    pub name: Option<String>,
     #[serde(rename = "self")]
     pub self_link: String,
     #[serde(rename = "timeZone")]
     pub timezone: Option<String>,
 }
 
+/// a permission, and whether the current user holds it
+#[derive(Deserialize, Debug)]
+pub struct Permission {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub permission_type: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "havePermission")]
+    pub have_permission: bool,
+}
+
+/// the permissions of the current user
+#[derive(Deserialize, Debug)]
+pub(crate) struct Permissions {
+    pub permissions: HashMap<String, Permission>,
+}
+
+/// values jira suggests for completing a jql clause
+#[derive(Deserialize, Debug)]
+pub(crate) struct Suggestions {
+    #[serde(default)]
+    pub results: Vec<Suggestion>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct Suggestion {
+    pub value: String,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Status {
     pub description: String,
@@ -283,12 +448,27 @@ pub struct Status {
     pub name: String,
     #[serde(rename = "self")]
     pub self_link: String,
+    #[serde(rename = "statusCategory")]
+    pub status_category: Option<StatusCategory>,
+}
+
+/// the category of a status, which classifies statuses across differing workflows
+#[derive(Deserialize, Debug)]
+pub struct StatusCategory {
+    pub id: Option<u64>,
+    /// one of `new`, `indeterminate` or `done`
+    pub key: String,
+    #[serde(rename = "colorName")]
+    pub color_name: String,
+    pub name: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct Priority {
     pub id: String,
     pub name: String,
+    #[serde(rename = "iconUrl", default)]
+    pub icon_url: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -333,7 +513,7 @@ pub struct TransitionOptions {
     pub transitions: Vec<TransitionOption>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Clone, Debug)]
 pub struct TransitionTriggerOptions {
     pub transition: Transition,
     pub fields: BTreeMap<String, ::serde_json::Value>,
@@ -394,7 +574,13 @@ impl TransitionTriggerOptionsBuilder {
     where
         R: Into<String>,
     {
-        self.field("resolution", Resolution { name: name.into() });
+        self.field(
+            "resolution",
+            Resolution {
+                id: None,
+                name: name.into(),
+            },
+        );
         self
     }
 
@@ -408,7 +594,9 @@ impl TransitionTriggerOptionsBuilder {
 
 #[derive(Serialize, Debug, Deserialize)]
 pub struct Resolution {
-    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
 }
 
 #[derive(Serialize, Clone, Debug)]
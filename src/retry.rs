@@ -0,0 +1,148 @@
+//! Retrying of rate limited requests and transient server errors
+
+// Third party
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// delay before the first retry when jira doesn't suggest one, doubled for each further retry
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+
+/// the longest delay between retries when jira doesn't suggest one
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// decides which responses are retried, and how long to wait in between
+#[derive(Clone, Debug)]
+pub(crate) struct RetryPolicy {
+    pub(crate) max_retries: u32,
+    /// whether requests which may not be safely repeated, like `POST`, are retried
+    pub(crate) non_idempotent: bool,
+    /// whether gateway errors, `502` and `504`, are retried along with `503`
+    pub(crate) server_errors: bool,
+    pub(crate) backoff_base: Duration,
+    pub(crate) backoff_cap: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 0,
+            non_idempotent: false,
+            server_errors: true,
+            backoff_base: BASE_BACKOFF,
+            backoff_cap: MAX_BACKOFF,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// whether the `attempt`th retry of a request receiving `status` should be made
+    pub(crate) fn retries(&self, method: &Method, status: StatusCode, attempt: u32) -> bool {
+        attempt < self.max_retries
+            && (status == StatusCode::TOO_MANY_REQUESTS
+                || status == StatusCode::SERVICE_UNAVAILABLE
+                || (self.server_errors
+                    && (status == StatusCode::BAD_GATEWAY
+                        || status == StatusCode::GATEWAY_TIMEOUT)))
+            && (self.non_idempotent || idempotent(method))
+    }
+
+    /// how long to wait before the `attempt`th retry, as long as jira's `Retry-After` header
+    /// asks or otherwise backing off exponentially
+    pub(crate) fn delay(&self, headers: &HeaderMap, attempt: u32) -> Duration {
+        headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(retry_after)
+            .unwrap_or_else(|| self.backoff(attempt))
+    }
+
+    /// the base delay doubled for each retry and bounded by the cap, with jitter of up to
+    /// half of it so that clients failing together don't retry together
+    fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .backoff_base
+            .checked_mul(2u32.saturating_pow(attempt))
+            .map_or(self.backoff_cap, |delay| delay.min(self.backoff_cap));
+        let half = delay / 2;
+        let jitter = half.as_nanos() as u64;
+        if jitter == 0 {
+            return delay;
+        }
+        half + Duration::from_nanos(random() % (jitter + 1))
+    }
+}
+
+/// a random number, drawn from the randomly seeded hasher std keys hash maps with
+fn random() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|now| now.subsec_nanos() as u64)
+            .unwrap_or_default(),
+    );
+    hasher.finish()
+}
+
+fn idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+/// interprets a `Retry-After` value, given either in seconds or as an http date
+fn retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = http_date(value)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(Duration::from_secs(at.saturating_sub(now)))
+}
+
+/// parses an imf-fixdate, e.g. `Wed, 21 Oct 2015 07:28:00 GMT`, into seconds since the epoch
+fn http_date(value: &str) -> Option<u64> {
+    let mut parts = value.split_whitespace();
+    let _weekday = parts.next()?;
+    let day = parts.next()?.parse::<u64>().ok()?;
+    let month = match parts.next()? {
+        "Jan" => 1,
+        "Feb" => 2,
+        "Mar" => 3,
+        "Apr" => 4,
+        "May" => 5,
+        "Jun" => 6,
+        "Jul" => 7,
+        "Aug" => 8,
+        "Sep" => 9,
+        "Oct" => 10,
+        "Nov" => 11,
+        "Dec" => 12,
+        _ => return None,
+    };
+    let year = parts.next()?.parse::<u64>().ok()?;
+    let mut time = parts.next()?.split(':').map(|t| t.parse::<u64>().ok());
+    let (hours, minutes, seconds) = (time.next()??, time.next()??, time.next()??);
+    if parts.next()? != "GMT" || year < 1970 {
+        return None;
+    }
+
+    // days since the epoch for a date in the proleptic gregorian calendar
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = y / 400;
+    let year_of_era = y % 400;
+    let day_of_year = (153 * m + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = (era * 146_097 + day_of_era).checked_sub(719_468)?;
+
+    Some(days * 86_400 + hours * 3_600 + minutes * 60 + seconds)
+}
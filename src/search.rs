@@ -4,7 +4,7 @@
 use url::form_urlencoded;
 
 // Ours
-use {Issue, Jira, Result, SearchOptions, SearchResults};
+use {IssuesIter, Jira, Result, SearchOptions, SearchResults};
 
 /// Search interface
 #[derive(Debug)]
@@ -35,69 +35,15 @@ impl Search {
             .get::<SearchResults>("api", path.join("?").as_ref())
     }
 
-    /// Return a type which may be used to iterate over consecutive pages of results
+    /// Return a type which may be used to iterate over consecutive pages of results.
+    /// No request is made until the iterator is first advanced
     ///
     /// See the [jira docs](https://docs.atlassian.com/jira/REST/latest/#api/2/search)
     /// for more information
-    pub fn iter<'a, J>(&self, jql: J, options: &'a SearchOptions) -> Result<Iter<'a>>
+    pub fn iter<'a, J>(&self, jql: J, options: &'a SearchOptions) -> IssuesIter<'a>
     where
         J: Into<String>,
     {
-        Iter::new(jql, options, &self.jira)
-    }
-}
-
-/// provides an iterator over multiple pages of search results
-#[derive(Debug)]
-pub struct Iter<'a> {
-    jira: Jira,
-    jql: String,
-    results: SearchResults,
-    search_options: &'a SearchOptions,
-}
-
-impl<'a> Iter<'a> {
-    fn new<J>(jql: J, options: &'a SearchOptions, jira: &Jira) -> Result<Self>
-    where
-        J: Into<String>,
-    {
-        let query = jql.into();
-        let results = jira.search().list(query.clone(), options)?;
-        Ok(Iter {
-            jira: jira.clone(),
-            jql: query,
-            results,
-            search_options: options,
-        })
-    }
-
-    fn more(&self) -> bool {
-        (self.results.start_at + self.results.issues.len() as u64) < self.results.total
-    }
-}
-
-impl<'a> Iterator for Iter<'a> {
-    type Item = Issue;
-    fn next(&mut self) -> Option<Issue> {
-        self.results.issues.pop().or_else(|| {
-            if self.more() {
-                match self.jira.search().list(
-                    self.jql.clone(),
-                    &self.search_options
-                        .as_builder()
-                        .max_results(self.results.max_results)
-                        .start_at(self.results.start_at + self.results.max_results)
-                        .build(),
-                ) {
-                    Ok(new_results) => {
-                        self.results = new_results;
-                        self.results.issues.pop()
-                    }
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        })
+        self.jira.issues().search_iter(&jql.into(), options)
     }
 }
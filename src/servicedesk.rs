@@ -0,0 +1,138 @@
+//! Interfaces for jira service management customer requests, through the service desk api
+
+// Third party
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+// Ours
+use {AddComment, Jira, Result};
+
+/// service desk options
+#[derive(Debug)]
+pub struct ServiceDesk {
+    jira: Jira,
+}
+
+/// a page of service desk api results, which pages differently to the core api
+#[derive(Deserialize, Debug)]
+pub struct ServiceDeskPage<T> {
+    pub size: u64,
+    pub start: u64,
+    pub limit: u64,
+    #[serde(rename = "isLastPage")]
+    pub is_last_page: bool,
+    pub values: Vec<T>,
+}
+
+/// a kind of request customers may raise with a service desk
+#[derive(Deserialize, Debug)]
+pub struct RequestType {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "helpText")]
+    pub help_text: Option<String>,
+    #[serde(rename = "issueTypeId")]
+    pub issue_type_id: Option<String>,
+    #[serde(rename = "serviceDeskId")]
+    pub service_desk_id: Option<String>,
+}
+
+/// a new customer request
+#[derive(Serialize, Debug, Default)]
+pub struct CreateCustomerRequest {
+    #[serde(rename = "serviceDeskId")]
+    pub service_desk_id: String,
+    #[serde(rename = "requestTypeId")]
+    pub request_type_id: String,
+    /// field values keyed by field id, e.g. `summary`
+    #[serde(rename = "requestFieldValues")]
+    pub request_field_values: BTreeMap<String, Value>,
+    /// the customer to raise the request for, when not the current user
+    #[serde(rename = "raiseOnBehalfOf", skip_serializing_if = "Option::is_none")]
+    pub raise_on_behalf_of: Option<String>,
+}
+
+impl CreateCustomerRequest {
+    pub fn new<S, R>(service_desk_id: S, request_type_id: R) -> CreateCustomerRequest
+    where
+        S: Into<String>,
+        R: Into<String>,
+    {
+        CreateCustomerRequest {
+            service_desk_id: service_desk_id.into(),
+            request_type_id: request_type_id.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// a customer request, backed by an issue
+#[derive(Deserialize, Debug)]
+pub struct CustomerRequest {
+    #[serde(rename = "issueId")]
+    pub issue_id: String,
+    #[serde(rename = "issueKey")]
+    pub issue_key: String,
+    #[serde(rename = "requestTypeId")]
+    pub request_type_id: String,
+    #[serde(rename = "serviceDeskId")]
+    pub service_desk_id: String,
+    #[serde(rename = "currentStatus")]
+    pub current_status: Option<CustomerRequestStatus>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CustomerRequestStatus {
+    pub status: String,
+}
+
+/// a comment on a customer request
+#[derive(Deserialize, Debug)]
+pub struct RequestComment {
+    pub id: String,
+    pub body: String,
+    /// whether customers may see the comment
+    pub public: bool,
+}
+
+impl ServiceDesk {
+    pub fn new(jira: &Jira) -> ServiceDesk {
+        ServiceDesk { jira: jira.clone() }
+    }
+
+    /// raises a customer request
+    /// https://docs.atlassian.com/jira-servicedesk/REST/latest/#servicedeskapi/request-createCustomerRequest
+    pub fn create_request(&self, request: CreateCustomerRequest) -> Result<CustomerRequest> {
+        self.jira.post("servicedeskapi", "/request", request)
+    }
+
+    /// returns the kinds of request customers may raise with a service desk
+    /// https://docs.atlassian.com/jira-servicedesk/REST/latest/#servicedeskapi/servicedesk/{serviceDeskId}/requesttype-getCustomerRequestTypes
+    pub fn request_types<I>(&self, service_desk_id: I) -> Result<ServiceDeskPage<RequestType>>
+    where
+        I: ToString,
+    {
+        self.jira.get(
+            "servicedeskapi",
+            &format!("/servicedesk/{}/requesttype", service_desk_id.to_string()),
+        )
+    }
+
+    /// comments on a customer request. comments which are not `public` are internal,
+    /// visible only to agents
+    /// https://docs.atlassian.com/jira-servicedesk/REST/latest/#servicedeskapi/request/{issueIdOrKey}/comment-createRequestComment
+    pub fn add_comment<I, B>(&self, issue: I, body: B, public: bool) -> Result<RequestComment>
+    where
+        I: Into<String>,
+        B: Into<String>,
+    {
+        let mut comment = AddComment::new(body.into());
+        comment.public = Some(public);
+        self.jira.post(
+            "servicedeskapi",
+            &format!("/request/{}/comment", issue.into()),
+            comment,
+        )
+    }
+}
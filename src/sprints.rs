@@ -1,10 +1,8 @@
 //! Interfaces for accessing and managing sprints
 
-// Third party
-use url::form_urlencoded;
-
 // Ours
-use {Board, EmptyResponse, Jira, Result, SearchOptions};
+use pagination::Fetch;
+use {Board, EmptyResponse, Jira, PageIter, Paginated, Result, SearchOptions};
 
 #[derive(Debug)]
 pub struct Sprints {
@@ -28,20 +26,53 @@ pub struct Sprint {
     pub origin_board_id: Option<u64>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct SprintResults {
-    #[serde(rename = "maxResults")]
-    pub max_results: u64,
-    #[serde(rename = "startAt")]
-    pub start_at: u64,
-    #[serde(rename = "isLast")]
-    pub is_last: bool,
-    pub values: Vec<Sprint>,
+/// a new sprint. sprints are created in the `future` state
+#[derive(Serialize, Debug)]
+pub struct CreateSprint {
+    pub name: String,
+    #[serde(rename = "originBoardId")]
+    pub origin_board_id: u64,
+    #[serde(rename = "startDate", skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(rename = "endDate", skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+}
+
+impl CreateSprint {
+    pub fn new<N>(name: N, origin_board_id: u64) -> CreateSprint
+    where
+        N: Into<String>,
+    {
+        CreateSprint {
+            name: name.into(),
+            origin_board_id,
+            start_date: None,
+            end_date: None,
+        }
+    }
+}
+
+/// a partial set of fields to edit on an existing sprint.
+/// fields left as `None` are left untouched on the server
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateSprint {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// one of `future`, `active` or `closed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(rename = "startDate", skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(rename = "endDate", skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
 }
 
+/// the most issues jira will move in a single request
+pub(crate) const MAX_MOVED_ISSUES: usize = 50;
+
 #[derive(Serialize, Debug)]
-struct MoveIssues {
-    issues: Vec<String>,
+pub(crate) struct MoveIssues {
+    pub(crate) issues: Vec<String>,
 }
 
 impl Sprints {
@@ -51,84 +82,90 @@ impl Sprints {
 
     /// returns a single page of board results
     /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board/{boardId}/sprint-getAllSprints
-    pub fn list(&self, board: &Board, options: &SearchOptions) -> Result<SprintResults> {
-        let mut path = vec![format!("/board/{}/sprint", board.id.to_string())];
-        let query_options = options.serialize().unwrap_or_default();
-        let query = form_urlencoded::Serializer::new(query_options).finish();
+    pub fn list(&self, board: &Board, options: &SearchOptions) -> Result<Paginated<Sprint>> {
+        self.jira.boards().sprints(board.id, options)
+    }
 
-        path.push(query);
+    /// returns a single sprint
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/sprint-getSprint
+    pub fn get(&self, sprint_id: u64) -> Result<Sprint> {
+        self.jira.get("agile", &format!("/sprint/{}", sprint_id))
+    }
+
+    /// creates a future sprint on a board
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/sprint-createSprint
+    pub fn create(&self, data: CreateSprint) -> Result<Sprint> {
+        self.jira.post("agile", "/sprint", data)
+    }
 
+    /// edits the fields of an existing sprint, e.g. to start or close it
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/sprint-partiallyUpdateSprint
+    pub fn update(&self, sprint_id: u64, data: UpdateSprint) -> Result<Sprint> {
         self.jira
-            .get::<SprintResults>("agile", path.join("?").as_ref())
+            .post("agile", &format!("/sprint/{}", sprint_id), data)
     }
 
-    /// move issues into sprint
+    /// move issues into sprint. jira accepts at most 50 issues per request,
+    /// so larger lists are moved in several requests
     /// https://docs.atlassian.com/jira-software/REST/7.3.1/#agile/1.0/sprint-moveIssuesToSprint
     pub fn move_issues(&self, sprint_id: u64, issues: Vec<String>) -> Result<EmptyResponse> {
         let path = format!("/sprint/{}/issue", sprint_id);
-        let data = MoveIssues { issues };
 
-        self.jira.post("agile", &path, data)
+        self.move_to(&path, issues)
     }
 
-    /// runs a type why may be used to iterate over consecutive pages of results
-    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board-getAllBoards
-    pub fn iter<'a>(
-        &self,
-        board: &'a Board,
-        options: &'a SearchOptions,
-    ) -> Result<SprintsIter<'a>> {
-        SprintsIter::new(board, options, &self.jira)
+    /// move issues out of any sprint and into the backlog. like `move_issues`,
+    /// larger lists are moved 50 issues at a time
+    /// https://docs.atlassian.com/jira-software/REST/7.3.1/#agile/1.0/backlog-moveIssuesToBacklog
+    pub fn backlog(&self, issues: Vec<String>) -> Result<EmptyResponse> {
+        self.move_to("/backlog/issue", issues)
+    }
+
+    fn move_to(&self, path: &str, issues: Vec<String>) -> Result<EmptyResponse> {
+        for chunk in issues.chunks(MAX_MOVED_ISSUES) {
+            let data = MoveIssues {
+                issues: chunk.to_vec(),
+            };
+            self.jira.post::<EmptyResponse, _>("agile", path, data)?;
+        }
+        Ok(EmptyResponse)
+    }
+
+    /// runs a type why may be used to iterate over consecutive pages of results.
+    /// no request is made until the iterator is first advanced
+    /// https://docs.atlassian.com/jira-software/REST/latest/#agile/1.0/board/{boardId}/sprint-getAllSprints
+    pub fn iter<'a>(&self, board: &'a Board, options: &'a SearchOptions) -> SprintsIter<'a> {
+        let jira = self.jira.clone();
+        SprintsIter {
+            pages: PageIter::new(
+                options,
+                Box::new(move |options| jira.sprints().list(board, options)),
+            ),
+        }
     }
 }
 
 /// provides an iterator over multiple pages of search results
 #[derive(Debug)]
 pub struct SprintsIter<'a> {
-    jira: Jira,
-    board: &'a Board,
-    results: SprintResults,
-    search_options: &'a SearchOptions,
+    pages: PageIter<Sprint, Fetch<'a, Sprint>>,
 }
 
 impl<'a> SprintsIter<'a> {
-    fn new(board: &'a Board, options: &'a SearchOptions, jira: &Jira) -> Result<Self> {
-        let results = jira.sprints().list(board, options)?;
-        Ok(SprintsIter {
-            board,
-            jira: jira.clone(),
-            results,
-            search_options: options,
-        })
+    /// the number of sprints fetched so far, including any not yet iterated over
+    pub fn fetched_so_far(&self) -> u64 {
+        self.pages.fetched_so_far()
     }
 
-    fn more(&self) -> bool {
-        !self.results.is_last
+    /// whether the last page of sprints has been fetched
+    pub fn is_last_page(&self) -> bool {
+        self.pages.is_last_page()
     }
 }
 
 impl<'a> Iterator for SprintsIter<'a> {
-    type Item = Sprint;
-    fn next(&mut self) -> Option<Sprint> {
-        self.results.values.pop().or_else(|| {
-            if self.more() {
-                match self.jira.sprints().list(
-                    self.board,
-                    &self.search_options
-                        .as_builder()
-                        .max_results(self.results.max_results)
-                        .start_at(self.results.start_at + self.results.max_results)
-                        .build(),
-                ) {
-                    Ok(new_results) => {
-                        self.results = new_results;
-                        self.results.values.pop()
-                    }
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        })
+    type Item = Result<Sprint>;
+    fn next(&mut self) -> Option<Result<Sprint>> {
+        self.pages.next()
     }
 }
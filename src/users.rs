@@ -0,0 +1,70 @@
+//! Interfaces for looking up users
+
+// Third party
+use url::form_urlencoded;
+
+// Ours
+use {Jira, Result, SearchOptions, User};
+
+/// user options
+#[derive(Debug)]
+pub struct Users {
+    jira: Jira,
+}
+
+impl Users {
+    pub fn new(jira: &Jira) -> Users {
+        Users { jira: jira.clone() }
+    }
+
+    /// returns a single user by account id, as used by jira cloud
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-users/#api-rest-api-2-user-get
+    pub fn get(&self, account_id: &str) -> Result<User> {
+        self.find_one("accountId", account_id)
+    }
+
+    /// returns a single user by username, as used by jira server
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/user-getUser
+    pub fn get_by_username(&self, username: &str) -> Result<User> {
+        self.find_one("username", username)
+    }
+
+    /// returns users matching a query against their display name or email address, as
+    /// supported by jira cloud
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-user-search/#api-rest-api-2-user-search-get
+    pub fn search(&self, query: &str, options: &SearchOptions) -> Result<Vec<User>> {
+        self.find_by("query", query, options)
+    }
+
+    /// returns users matching a query against their username, name or email address, as
+    /// supported by jira server
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/user-findUsers
+    pub fn search_by_username(&self, username: &str, options: &SearchOptions) -> Result<Vec<User>> {
+        self.find_by("username", username, options)
+    }
+
+    /// returns users matching a query, searching by username on jira server and by display
+    /// name or email address on jira cloud, as decided by `Jira::deployment`
+    pub fn find(&self, query: &str, options: &SearchOptions) -> Result<Vec<User>> {
+        self.find_by(self.jira.deployment().user_query(), query, options)
+    }
+
+    fn find_one(&self, param: &str, value: &str) -> Result<User> {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair(param, value)
+            .finish();
+        self.jira.get("api", &format!("/user?{}", query))
+    }
+
+    fn find_by(&self, param: &str, value: &str, options: &SearchOptions) -> Result<Vec<User>> {
+        let mut path = vec!["/user/search".to_owned()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair(param, value)
+            .finish();
+
+        path.push(query);
+
+        self.jira.get::<Vec<User>>("api", path.join("?").as_ref())
+    }
+}
@@ -0,0 +1,86 @@
+//! Interfaces for managing project versions
+
+// Ours
+use {Jira, Result, Version};
+
+/// version options
+#[derive(Debug)]
+pub struct Versions {
+    jira: Jira,
+}
+
+/// a new project version
+#[derive(Serialize, Debug, Default)]
+pub struct CreateVersion {
+    pub name: String,
+    /// the key of the project the version belongs to
+    pub project: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// formatted as `yyyy-mm-dd`
+    #[serde(rename = "releaseDate", skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    pub released: bool,
+}
+
+impl CreateVersion {
+    pub fn new<N, P>(name: N, project: P) -> CreateVersion
+    where
+        N: Into<String>,
+        P: Into<String>,
+    {
+        CreateVersion {
+            name: name.into(),
+            project: project.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// a partial set of fields to edit on an existing version.
+/// fields left as `None` are left untouched on the server
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateVersion {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// formatted as `yyyy-mm-dd`
+    #[serde(rename = "releaseDate", skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub released: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+impl Versions {
+    pub fn new(jira: &Jira) -> Versions {
+        Versions { jira: jira.clone() }
+    }
+
+    /// returns a single version
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/version-getVersion
+    pub fn get<I>(&self, id: I) -> Result<Version>
+    where
+        I: Into<String>,
+    {
+        self.jira.get("api", &format!("/version/{}", id.into()))
+    }
+
+    /// creates a version in a project
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/version-createVersion
+    pub fn create(&self, data: CreateVersion) -> Result<Version> {
+        self.jira.post("api", "/version", data)
+    }
+
+    /// edits an existing version, e.g. to release it
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/version-updateVersion
+    pub fn update<I>(&self, id: I, data: UpdateVersion) -> Result<Version>
+    where
+        I: Into<String>,
+    {
+        self.jira
+            .put("api", &format!("/version/{}", id.into()), data)
+    }
+}
@@ -0,0 +1,63 @@
+//! Interfaces for accessing and casting votes on issues
+
+// Third party
+use reqwest::Method;
+
+// Ours
+use {Jira, Result, User};
+
+/// votes interface for a single issue
+#[derive(Debug)]
+pub struct Votes {
+    jira: Jira,
+    key: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct VotesInfo {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub votes: u64,
+    #[serde(rename = "hasVoted")]
+    pub has_voted: bool,
+    /// only listed when the current user may view voters, and never in an issue's fields
+    #[serde(default)]
+    pub voters: Vec<User>,
+}
+
+impl Votes {
+    pub fn new<K>(jira: &Jira, key: K) -> Votes
+    where
+        K: Into<String>,
+    {
+        Votes {
+            jira: jira.clone(),
+            key: key.into(),
+        }
+    }
+
+    /// returns the issue's vote count and, permissions allowing, its voters
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getVotes
+    pub fn get(&self) -> Result<VotesInfo> {
+        self.jira
+            .get("api", &format!("/issue/{}/votes", self.key))
+    }
+
+    /// casts the current user's vote for the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-addVote
+    pub fn add(&self) -> Result<()> {
+        self.jira.request(
+            Method::POST,
+            "api",
+            &format!("/issue/{}/votes", self.key),
+            None,
+        )
+    }
+
+    /// withdraws the current user's vote for the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-removeVote
+    pub fn remove(&self) -> Result<()> {
+        self.jira
+            .delete("api", &format!("/issue/{}/votes", self.key))
+    }
+}
@@ -0,0 +1,68 @@
+//! Interfaces for accessing and managing issue watchers
+
+// Third party
+use url::form_urlencoded;
+
+// Ours
+use {Jira, Result, User};
+
+/// watchers interface for a single issue
+#[derive(Debug)]
+pub struct Watchers {
+    jira: Jira,
+    key: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct WatcherList {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    #[serde(rename = "isWatching")]
+    pub is_watching: bool,
+    #[serde(rename = "watchCount")]
+    pub watch_count: u64,
+    pub watchers: Vec<User>,
+}
+
+impl Watchers {
+    pub fn new<K>(jira: &Jira, key: K) -> Watchers
+    where
+        K: Into<String>,
+    {
+        Watchers {
+            jira: jira.clone(),
+            key: key.into(),
+        }
+    }
+
+    /// returns the users watching the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getIssueWatchers
+    pub fn list(&self) -> Result<WatcherList> {
+        self.jira
+            .get("api", &format!("/issue/{}/watchers", self.key))
+    }
+
+    /// adds a user to the issue's watchers
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-addWatcher
+    pub fn add<U>(&self, user: U) -> Result<()>
+    where
+        U: Into<String>,
+    {
+        // jira expects the username as a bare json string body
+        self.jira
+            .post("api", &format!("/issue/{}/watchers", self.key), user.into())
+    }
+
+    /// removes a user from the issue's watchers
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-removeWatcher
+    pub fn remove<U>(&self, user: U) -> Result<()>
+    where
+        U: Into<String>,
+    {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("username", &user.into())
+            .finish();
+        self.jira
+            .delete("api", &format!("/issue/{}/watchers?{}", self.key, query))
+    }
+}
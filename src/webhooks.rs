@@ -0,0 +1,112 @@
+//! Interfaces for managing dynamic webhooks, as registered by jira cloud apps
+
+// Third party
+use reqwest::Method;
+use serde_json;
+use url::form_urlencoded;
+
+// Ours
+use {Jira, Paginated, Result, SearchOptions};
+
+/// webhook options
+#[derive(Debug)]
+pub struct Webhooks {
+    jira: Jira,
+}
+
+/// webhooks to register, all delivering events to the same url
+#[derive(Serialize, Debug, Default)]
+pub struct RegisterWebhook {
+    pub url: String,
+    pub webhooks: Vec<WebhookDetails>,
+}
+
+/// the events a webhook is sent for, and the issues it is limited to
+#[derive(Serialize, Debug, Default)]
+pub struct WebhookDetails {
+    /// limits events to issues matching a jql query, e.g. `project = TEST`
+    #[serde(rename = "jqlFilter")]
+    pub jql_filter: String,
+    /// e.g. `jira:issue_created` or `comment_created`
+    pub events: Vec<String>,
+}
+
+/// the outcome of registering each of the requested webhooks, in the order they were requested
+#[derive(Deserialize, Debug)]
+pub struct RegisterWebhookResult {
+    #[serde(rename = "webhookRegistrationResult", default)]
+    pub webhook_registration_result: Vec<WebhookRegistration>,
+}
+
+/// the id of a registered webhook, or why it could not be registered
+#[derive(Deserialize, Debug)]
+pub struct WebhookRegistration {
+    #[serde(rename = "createdWebhookId")]
+    pub created_webhook_id: Option<u64>,
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
+/// a webhook registered by the calling app
+#[derive(Deserialize, Debug)]
+pub struct Webhook {
+    pub id: u64,
+    #[serde(rename = "jqlFilter")]
+    pub jql_filter: String,
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// when the webhook expires unless refreshed
+    #[serde(rename = "expirationDate")]
+    pub expiration_date: Option<String>,
+}
+
+/// the new expiry of refreshed webhooks
+#[derive(Deserialize, Debug)]
+pub struct WebhookRefresh {
+    #[serde(rename = "expirationDate")]
+    pub expiration_date: String,
+}
+
+#[derive(Serialize, Debug)]
+struct WebhookIds<'a> {
+    #[serde(rename = "webhookIds")]
+    webhook_ids: &'a [u64],
+}
+
+impl Webhooks {
+    pub fn new(jira: &Jira) -> Webhooks {
+        Webhooks { jira: jira.clone() }
+    }
+
+    /// registers webhooks for the calling app. they expire after 30 days unless refreshed
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-webhooks/#api-rest-api-2-webhook-post
+    pub fn register(&self, webhooks: RegisterWebhook) -> Result<RegisterWebhookResult> {
+        self.jira.post("api", "/webhook", webhooks)
+    }
+
+    /// returns a page of the webhooks registered by the calling app
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-webhooks/#api-rest-api-2-webhook-get
+    pub fn list(&self, options: &SearchOptions) -> Result<Paginated<Webhook>> {
+        let mut path = vec!["/webhook".to_owned()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options).finish();
+
+        path.push(query);
+
+        self.jira
+            .get::<Paginated<Webhook>>("api", path.join("?").as_ref())
+    }
+
+    /// removes webhooks registered by the calling app
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-webhooks/#api-rest-api-2-webhook-delete
+    pub fn delete(&self, ids: &[u64]) -> Result<()> {
+        let data = serde_json::to_vec(&WebhookIds { webhook_ids: ids })?;
+        self.jira.request(Method::DELETE, "api", "/webhook", Some(data))
+    }
+
+    /// extends the life of webhooks registered by the calling app by another 30 days
+    /// https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-webhooks/#api-rest-api-2-webhook-refresh-put
+    pub fn refresh(&self, ids: &[u64]) -> Result<WebhookRefresh> {
+        self.jira.put("api", "/webhook/refresh", WebhookIds { webhook_ids: ids })
+    }
+}
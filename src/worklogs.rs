@@ -0,0 +1,193 @@
+//! Interfaces for accessing and managing time tracked against issues
+
+// Third party
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, FixedOffset};
+use url::form_urlencoded;
+
+// Ours
+#[cfg(feature = "chrono")]
+use datetime;
+use {CommentBody, Jira, Paginated, Result, User};
+
+/// worklogs interface for a single issue
+#[derive(Debug)]
+pub struct Worklogs {
+    jira: Jira,
+    key: String,
+}
+
+/// represents time logged against an issue
+#[derive(Deserialize, Debug)]
+pub struct Worklog {
+    pub id: String,
+    #[serde(rename = "self")]
+    pub self_link: String,
+    pub author: Option<User>,
+    #[serde(rename = "updateAuthor")]
+    pub update_author: Option<User>,
+    pub comment: Option<CommentBody>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(deserialize_with = "datetime::deserialize_checked")
+    )]
+    pub started: String,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, deserialize_with = "datetime::deserialize_checked_option")
+    )]
+    pub created: Option<String>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, deserialize_with = "datetime::deserialize_checked_option")
+    )]
+    pub updated: Option<String>,
+    #[serde(rename = "timeSpent")]
+    pub time_spent: Option<String>,
+    #[serde(rename = "timeSpentSeconds")]
+    pub time_spent_seconds: u64,
+}
+
+#[cfg(feature = "chrono")]
+impl Worklog {
+    /// when the work started, parsed
+    pub fn started_at(&self) -> Option<DateTime<FixedOffset>> {
+        datetime::parse(&self.started)
+    }
+
+    /// created timestamp, parsed
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created.as_ref().and_then(|value| datetime::parse(value))
+    }
+
+    /// updated timestamp, parsed
+    pub fn updated_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.updated.as_ref().and_then(|value| datetime::parse(value))
+    }
+}
+
+/// how the remaining estimate of an issue changes when logging work
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AdjustEstimate {
+    /// sets the remaining estimate to a new value, `AddWorklog::new_estimate`
+    New,
+    /// leaves the remaining estimate unchanged
+    Leave,
+    /// reduces the remaining estimate by a given amount, `AddWorklog::reduce_by`
+    Manual,
+    /// reduces the remaining estimate by the time spent
+    Auto,
+}
+
+impl AdjustEstimate {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            AdjustEstimate::New => "new",
+            AdjustEstimate::Leave => "leave",
+            AdjustEstimate::Manual => "manual",
+            AdjustEstimate::Auto => "auto",
+        }
+    }
+}
+
+/// payload for logging work against an issue
+#[derive(Serialize, Debug, Default)]
+pub struct AddWorklog {
+    /// plain text with version 2 of the rest api, or a document with version 3
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<CommentBody>,
+    /// when work began, formatted like `2018-07-11T16:56:12.000+0000`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started: Option<String>,
+    #[serde(rename = "timeSpentSeconds")]
+    pub time_spent_seconds: u64,
+    /// sent as the `adjustEstimate` query parameter. jira defaults to `Auto`
+    #[serde(skip_serializing)]
+    pub adjust_estimate: Option<AdjustEstimate>,
+    /// the remaining estimate to set when adjusting it with `AdjustEstimate::New`,
+    /// in jira's duration format, e.g. `2d 4h`
+    #[serde(skip_serializing)]
+    pub new_estimate: Option<String>,
+    /// the amount to reduce the remaining estimate by when adjusting it with
+    /// `AdjustEstimate::Manual`, in jira's duration format, e.g. `90m`
+    #[serde(skip_serializing)]
+    pub reduce_by: Option<String>,
+}
+
+impl AddWorklog {
+    /// logs a number of seconds worked, starting now
+    pub fn new(time_spent_seconds: u64) -> AddWorklog {
+        AddWorklog {
+            time_spent_seconds,
+            ..Default::default()
+        }
+    }
+
+    /// sets the remaining estimate to `estimate` rather than reducing it by the time spent
+    pub fn new_estimate<E>(self, estimate: E) -> AddWorklog
+    where
+        E: Into<String>,
+    {
+        AddWorklog {
+            adjust_estimate: Some(AdjustEstimate::New),
+            new_estimate: Some(estimate.into()),
+            ..self
+        }
+    }
+
+    /// reduces the remaining estimate by `amount` rather than by the time spent
+    pub fn reduce_by<A>(self, amount: A) -> AddWorklog
+    where
+        A: Into<String>,
+    {
+        AddWorklog {
+            adjust_estimate: Some(AdjustEstimate::Manual),
+            reduce_by: Some(amount.into()),
+            ..self
+        }
+    }
+
+    fn query(&self) -> Option<String> {
+        self.adjust_estimate.map(|adjust| {
+            let mut query = form_urlencoded::Serializer::new(String::new());
+            query.append_pair("adjustEstimate", adjust.as_str());
+            if let Some(ref estimate) = self.new_estimate {
+                query.append_pair("newEstimate", estimate);
+            }
+            if let Some(ref amount) = self.reduce_by {
+                query.append_pair("reduceBy", amount);
+            }
+            query.finish()
+        })
+    }
+}
+
+impl Worklogs {
+    pub fn new<K>(jira: &Jira, key: K) -> Worklogs
+    where
+        K: Into<String>,
+    {
+        Worklogs {
+            jira: jira.clone(),
+            key: key.into(),
+        }
+    }
+
+    /// returns the worklogs of the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-getIssueWorklog
+    pub fn list(&self) -> Result<Paginated<Worklog>> {
+        self.jira
+            .get("api", &format!("/issue/{}/worklog", self.key))
+    }
+
+    /// logs work against the issue
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/latest/#api/2/issue-addWorklog
+    pub fn add(&self, entry: AddWorklog) -> Result<Worklog> {
+        let mut path = vec![format!("/issue/{}/worklog", self.key)];
+        if let Some(query) = entry.query() {
+            path.push(query);
+        }
+
+        self.jira.post("api", path.join("?").as_ref(), entry)
+    }
+}
@@ -0,0 +1,294 @@
+#![cfg(feature = "async")]
+
+extern crate futures;
+extern crate jira_rs;
+extern crate serde_json;
+extern crate tokio;
+extern crate url;
+
+mod support;
+
+use futures::Stream;
+use jira_rs::{ApiVersion, AsyncJira, Credentials, Error, Jira};
+use std::time::Duration;
+use support::{issues_page, MockServer, Response};
+use tokio::runtime::Runtime;
+
+fn jira(server: &MockServer) -> AsyncJira {
+    AsyncJira::new(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .unwrap()
+}
+
+#[test]
+fn get_issue() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#,
+    )]);
+
+    let issue = Runtime::new()
+        .unwrap()
+        .block_on(jira(&server).issues().get("TEST-1"))
+        .unwrap();
+
+    assert_eq!(issue.key, "TEST-1");
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/issue/TEST-1");
+}
+
+#[test]
+fn builds_from_builder_settings() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"self": "http://jira.com/rest/api/3/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#,
+    )]);
+    let jira = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .api_version(ApiVersion::V3)
+    .user_agent("release-bot/1.0")
+    .build_async()
+    .unwrap();
+
+    Runtime::new()
+        .unwrap()
+        .block_on(jira.issues().get("TEST-1"))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/rest/api/3/issue/TEST-1");
+    assert_eq!(request.header("User-Agent"), Some("release-bot/1.0"));
+}
+
+#[test]
+fn sends_the_default_user_agent() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#,
+    )]);
+
+    Runtime::new()
+        .unwrap()
+        .block_on(jira(&server).issues().get("TEST-1"))
+        .unwrap();
+
+    assert!(server.requests()[0]
+        .header("User-Agent")
+        .unwrap()
+        .starts_with("jira-rs/"));
+}
+
+#[test]
+fn slow_responses_time_out() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#,
+    )
+    .delay(Duration::from_secs(2))]);
+    let jira = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .timeout(Duration::from_millis(200))
+    .build_async()
+    .unwrap();
+
+    match Runtime::new()
+        .unwrap()
+        .block_on(jira.issues().get("TEST-1"))
+    {
+        Err(Error::Timeout) => (),
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+}
+
+fn retrying(server: &MockServer, retries: u32) -> AsyncJira {
+    Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .max_retries(retries)
+    .build_async()
+    .unwrap()
+}
+
+#[test]
+fn retries_rate_limited_requests() {
+    let server = MockServer::start(vec![
+        Response::new(429).header("Retry-After", "0"),
+        Response::json(
+            200,
+            r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#,
+        ),
+    ]);
+
+    let issue = Runtime::new()
+        .unwrap()
+        .block_on(retrying(&server, 3).issues().get("TEST-1"))
+        .unwrap();
+
+    assert_eq!(issue.key, "TEST-1");
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn never_retries_creating_issues_by_default() {
+    let server = MockServer::start(vec![Response::new(429).header("Retry-After", "0")]);
+
+    let create =
+        retrying(&server, 3).post::<serde_json::Value, _>("api", "/issue", serde_json::json!({}));
+
+    match Runtime::new().unwrap().block_on(create) {
+        Err(Error::Fault { code, .. }) => assert_eq!(code.as_u16(), 429),
+        other => panic!("expected a fault, got {:?}", other),
+    }
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn backs_off_from_gateway_errors() {
+    let server = MockServer::start(vec![
+        Response::new(503),
+        Response::new(503),
+        Response::json(
+            200,
+            r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#,
+        ),
+    ]);
+    let jira = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .max_retries(2)
+    .retry_backoff(Duration::from_millis(10), Duration::from_millis(50))
+    .build_async()
+    .unwrap();
+
+    let issue = Runtime::new()
+        .unwrap()
+        .block_on(jira.issues().get("TEST-1"))
+        .unwrap();
+
+    assert_eq!(issue.key, "TEST-1");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn gives_up_after_max_retries() {
+    let server = MockServer::start(vec![
+        Response::new(502).header("Retry-After", "0"),
+        Response::new(502).header("Retry-After", "0"),
+    ]);
+
+    match Runtime::new()
+        .unwrap()
+        .block_on(retrying(&server, 1).issues().get("TEST-1"))
+    {
+        Err(Error::Fault { code, .. }) => assert_eq!(code.as_u16(), 502),
+        other => panic!("expected a fault, got {:?}", other),
+    }
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn unversioned_apis_keep_their_own_paths() {
+    let server = MockServer::start(vec![
+        Response::json(
+            200,
+            r#"{"size": 0, "start": 0, "limit": 50, "isLastPage": true, "values": []}"#,
+        ),
+        Response::json(
+            200,
+            r#"{"id": 1, "self": "http://jira.com/rest/agile/1.0/board/1", "name": "board", "type": "scrum"}"#,
+        ),
+    ]);
+    let jira = jira(&server);
+    let mut runtime = Runtime::new().unwrap();
+
+    runtime
+        .block_on(jira.get::<serde_json::Value>("servicedeskapi", "/servicedesk"))
+        .unwrap();
+    runtime
+        .block_on(jira.get::<serde_json::Value>("agile", "/board/1"))
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/rest/servicedeskapi/servicedesk");
+    assert_eq!(requests[1].path, "/rest/agile/latest/board/1");
+}
+
+#[test]
+fn search_stream_pages_through_results() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 3, &[1, 2]),
+        issues_page(2, 2, 3, &[3]),
+    ]);
+
+    let ids = Runtime::new()
+        .unwrap()
+        .block_on(
+            jira(&server)
+                .issues()
+                .search_stream("project = TEST", &Default::default())
+                .map(|issue| issue.id)
+                .collect(),
+        )
+        .unwrap();
+
+    assert_eq!(ids, vec!["1", "2", "3"]);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].query("jql"), Some("project = TEST".to_owned()));
+    assert_eq!(requests[1].query("startAt"), Some("2".to_owned()));
+}
+
+#[test]
+fn search_stream_surfaces_errors() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 3, &[1, 2]),
+        Response::json(500, r#"{"errorMessages": ["boom"]}"#),
+    ]);
+
+    let result = Runtime::new().unwrap().block_on(
+        jira(&server)
+            .issues()
+            .search_stream("project = TEST", &Default::default())
+            .collect(),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn search_all_parallel_preserves_order() {
+    let server = MockServer::route(3, |request| {
+        match request.query("startAt").as_deref() {
+            Some("2") => issues_page(2, 2, 5, &[3, 4]),
+            Some("4") => issues_page(4, 2, 5, &[5]),
+            _ => issues_page(0, 2, 5, &[1, 2]),
+        }
+    });
+
+    let ids = Runtime::new()
+        .unwrap()
+        .block_on(jira(&server).issues().search_all_parallel(
+            "project = TEST",
+            &Default::default(),
+            3,
+        ))
+        .unwrap()
+        .into_iter()
+        .map(|issue| issue.id)
+        .collect::<Vec<String>>();
+
+    assert_eq!(ids, vec!["1", "2", "3", "4", "5"]);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].query("startAt"), None);
+    assert!(requests[1..]
+        .iter()
+        .all(|request| request.query("maxResults") == Some("2".to_owned())));
+}
@@ -1,18 +1,23 @@
-extern crate goji;
+extern crate jira_rs;
 extern crate serde_json;
+extern crate url;
 
-use goji::boards::*;
+mod support;
+
+use jira_rs::boards::*;
+use jira_rs::{Error, Paginated, SearchOptions};
+use support::{jira, MockServer, Response};
+
+const BOARD: &str = r#"{
+    "id": 1,
+    "self": "https://my.atlassian.net/rest/agile/1.0/board/1",
+    "name": "TEST board",
+    "type": "kanban"
+}"#;
 
 #[test]
 fn deserialise_board() {
-    let board_str = r#"{
-        "id": 1,
-        "self": "https://my.atlassian.net/rest/agile/1.0/board/1",
-        "name": "TEST board",
-        "type": "kanban"
-    }"#;
-
-    let board: Board = serde_json::from_str(board_str).unwrap();
+    let board: Board = serde_json::from_str(BOARD).unwrap();
 
     assert_eq!(board.id, 1u64);
     assert_eq!(
@@ -38,10 +43,203 @@ fn deserialise_board_results() {
         }]
     }"#;
 
-    let board_results: BoardResults = serde_json::from_str(board_results_str).unwrap();
+    let board_results: Paginated<Board> = serde_json::from_str(board_results_str).unwrap();
 
     assert_eq!(board_results.max_results, 50u64);
     assert_eq!(board_results.start_at, 0u64);
-    assert_eq!(board_results.is_last, true);
+    assert_eq!(board_results.is_last, Some(true));
     assert_eq!(board_results.values.len(), 1);
 }
+
+fn boards_page(start_at: u64, max_results: u64, is_last: bool, ids: &[u64]) -> String {
+    let values = ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"id": {0}, "self": "http://jira.com/rest/agile/1.0/board/{0}", "name": "board {0}", "type": "scrum"}}"#,
+                id
+            )
+        })
+        .collect::<Vec<String>>();
+    format!(
+        r#"{{"maxResults": {}, "startAt": {}, "isLast": {}, "values": [{}]}}"#,
+        max_results,
+        start_at,
+        is_last,
+        values.join(",")
+    )
+}
+
+#[test]
+fn list_boards_for_project() {
+    let server = MockServer::start(vec![Response::json(200, &boards_page(0, 50, true, &[7]))]);
+
+    let boards = jira(&server)
+        .boards()
+        .list(
+            &SearchOptions::builder()
+                .project_key_or_id("TEST")
+                .type_name("scrum")
+                .build(),
+        )
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/agile/latest/board");
+    assert_eq!(request.query("projectKeyOrId"), Some("TEST".to_owned()));
+    assert_eq!(request.query("type"), Some("scrum".to_owned()));
+    assert_eq!(boards.values[0].id, 7);
+}
+
+#[test]
+fn get_board_by_id() {
+    let server = MockServer::start(vec![Response::json(200, BOARD)]);
+
+    let board = jira(&server).boards().get(1).unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/agile/latest/board/1");
+    assert_eq!(board.name, "TEST board");
+}
+
+#[test]
+fn iter_boards_in_server_order() {
+    let server = MockServer::start(vec![
+        Response::json(200, &boards_page(0, 2, false, &[1, 2])),
+        Response::json(200, &boards_page(2, 2, true, &[3])),
+    ]);
+    let options = SearchOptions::default();
+
+    let ids = jira(&server)
+        .boards()
+        .iter(&options)
+        .map(|board| board.unwrap().id)
+        .collect::<Vec<u64>>();
+
+    assert_eq!(ids, vec![1, 2, 3]);
+    assert_eq!(server.requests()[1].query("startAt"), Some("2".to_owned()));
+}
+
+#[test]
+fn iter_boards_yields_failed_pages_as_errors() {
+    let server = MockServer::start(vec![
+        Response::json(200, &boards_page(0, 2, false, &[1, 2])),
+        Response::json(
+            500,
+            r#"{"errorMessages": ["Internal server error"], "errors": {}}"#,
+        ),
+    ]);
+    let options = SearchOptions::default();
+
+    let results = jira(&server).boards().iter(&options).collect::<Vec<_>>();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().id, 1);
+    assert_eq!(results[1].as_ref().unwrap().id, 2);
+    match results[2] {
+        Err(Error::Fault { ref code, .. }) => assert_eq!(code.as_u16(), 500),
+        ref other => panic!("expected a fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn iter_boards_continues_after_a_page_cut_short_by_the_server() {
+    let server = MockServer::start(vec![
+        Response::json(200, &boards_page(0, 50, false, &[1, 2])),
+        Response::json(200, &boards_page(2, 50, true, &[3])),
+    ]);
+    let options = SearchOptions::builder().max_results(100).build();
+
+    let ids = jira(&server)
+        .boards()
+        .iter(&options)
+        .map(|board| board.unwrap().id)
+        .collect::<Vec<u64>>();
+
+    assert_eq!(ids, vec![1, 2, 3]);
+    let requests = server.requests();
+    assert_eq!(requests[1].query("startAt"), Some("2".to_owned()));
+    assert_eq!(requests[1].query("maxResults"), Some("50".to_owned()));
+}
+
+#[test]
+fn board_backlog() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"startAt": 0, "maxResults": 50, "total": 1, "issues": [
+            {"self": "http://jira.com/rest/api/2/issue/10001", "id": "10001", "key": "TEST-2", "fields": {}}
+        ]}"#,
+    )]);
+
+    let backlog = jira(&server)
+        .boards()
+        .backlog(84, &Default::default())
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].path_only(),
+        "/rest/agile/latest/board/84/backlog"
+    );
+    assert_eq!(backlog.values[0].key, "TEST-2");
+}
+
+#[test]
+fn board_configuration() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "id": 84,
+            "name": "scrum board",
+            "self": "http://jira.com/rest/agile/1.0/board/84/configuration",
+            "filter": {"id": "1001", "self": "http://jira.com/filter/1001"},
+            "columnConfig": {
+                "columns": [
+                    {
+                        "name": "To Do",
+                        "statuses": [
+                            {"id": "1", "self": "http://jira.com/status/1"},
+                            {"id": "4", "self": "http://jira.com/status/4"}
+                        ]
+                    },
+                    {
+                        "name": "Done",
+                        "statuses": [
+                            {"id": "5", "self": "http://jira.com/status/5"},
+                            {"id": "6", "self": "http://jira.com/status/6"}
+                        ],
+                        "max": 4
+                    }
+                ],
+                "constraintType": "issueCount"
+            },
+            "estimation": {
+                "type": "field",
+                "field": {"fieldId": "customfield_10002", "displayName": "Story Points"}
+            },
+            "ranking": {"rankCustomFieldId": 10011}
+        }"#,
+    )]);
+
+    let config = jira(&server).boards().configuration(84).unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/agile/latest/board/84/configuration"
+    );
+    assert_eq!(config.filter.id, "1001");
+    let columns = &config.column_config.columns;
+    assert_eq!(columns.len(), 2);
+    assert_eq!(columns[0].name, "To Do");
+    let ids = |column: &BoardColumn| {
+        column
+            .statuses
+            .iter()
+            .map(|status| status.id.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&columns[0]), vec!["1", "4"]);
+    assert_eq!(ids(&columns[1]), vec!["5", "6"]);
+    assert_eq!(columns[1].max, Some(4));
+    let estimation = config.estimation.unwrap();
+    assert_eq!(estimation.estimation_type, "field");
+    assert_eq!(estimation.field.unwrap().field_id, "customfield_10002");
+}
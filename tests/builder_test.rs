@@ -1,8 +1,8 @@
-extern crate goji;
+extern crate jira_rs;
 extern crate serde_json;
 extern crate url;
 
-use goji::*;
+use jira_rs::*;
 use std::collections::HashMap;
 use url::form_urlencoded;
 
@@ -42,4 +42,6 @@ builder_pattern! {
     build_pattern_state: (state, "my_state", "state","my_state"),
     build_pattern_jql: (jql, "project = '1234'", "jql", "project = '1234'"),
     build_pattern_jalidate_query: (validate_query, true, "validateQuery", "true"),
+    build_pattern_project_keys: (project_keys, vec!["TEST", "OTHER"], "projectKeys", "TEST,OTHER"),
+    build_pattern_issue_type_names: (issue_type_names, vec!["Bug", "Task"], "issuetypeNames", "Bug,Task"),
 }
@@ -0,0 +1,234 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+const COMMENT: &str = r#"{
+    "self": "http://jira.com/rest/api/2/issue/10010/comment/10000",
+    "id": "10000",
+    "body": "Lorem ipsum dolor sit amet.",
+    "created": "2018-07-11T16:56:12.000+0000",
+    "updated": "2018-07-11T16:56:12.000+0000",
+    "visibility": {
+        "type": "role",
+        "value": "Administrators"
+    }
+}"#;
+
+#[test]
+fn list_comments() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        &format!(
+            r#"{{"startAt": 0, "maxResults": 1, "total": 1, "comments": [{}]}}"#,
+            COMMENT
+        ),
+    )]);
+
+    let comments = jira(&server)
+        .issues()
+        .comments("TEST-1")
+        .list(&SearchOptions::builder().max_results(1).build())
+        .unwrap();
+
+    assert_eq!(comments.total, 1);
+    assert_eq!(comments.values[0].id, Some("10000".to_owned()));
+    assert_eq!(
+        comments.values[0].body.as_plain(),
+        Some("Lorem ipsum dolor sit amet.")
+    );
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/api/latest/issue/TEST-1/comment");
+    assert_eq!(request.query("maxResults"), Some("1".to_owned()));
+}
+
+#[test]
+fn add_restricted_comment() {
+    let server = MockServer::start(vec![Response::json(201, COMMENT)]);
+
+    let comment = jira(&server)
+        .issues()
+        .comments("TEST-1")
+        .add(AddComment {
+            body: "Lorem ipsum dolor sit amet.".into(),
+            visibility: Some(Visibility {
+                visibility_type: "role".to_owned(),
+                value: "Administrators".to_owned(),
+            }),
+            public: None,
+        })
+        .unwrap();
+
+    assert_eq!(comment.id, Some("10000".to_owned()));
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "body": "Lorem ipsum dolor sit amet.",
+            "visibility": { "type": "role", "value": "Administrators" }
+        })
+    );
+}
+
+#[test]
+fn issue_comment_field() {
+    let issue: Issue = serde_json::from_str(&format!(
+        r#"{{
+            "self": "http://jira.com/rest/api/2/issue/10010",
+            "id": "10010",
+            "key": "TEST-1",
+            "fields": {{
+                "comment": {{"startAt": 0, "maxResults": 1, "total": 1, "comments": [{}]}}
+            }}
+        }}"#,
+        COMMENT
+    ))
+    .unwrap();
+
+    assert_eq!(issue.comment().len(), 1);
+}
+
+#[test]
+fn plain_text_body_round_trips() {
+    let body: CommentBody = serde_json::from_str(r#""Lorem ipsum dolor sit amet.""#).unwrap();
+
+    assert_eq!(body, CommentBody::from("Lorem ipsum dolor sit amet."));
+    assert_eq!(
+        serde_json::to_value(&body).unwrap(),
+        serde_json::json!("Lorem ipsum dolor sit amet.")
+    );
+}
+
+#[test]
+fn document_body_round_trips() {
+    let document = serde_json::json!({
+        "version": 1,
+        "type": "doc",
+        "content": [{
+            "type": "paragraph",
+            "content": [{ "type": "text", "text": "Lorem ipsum dolor sit amet." }]
+        }]
+    });
+
+    let body: CommentBody = serde_json::from_value(document.clone()).unwrap();
+
+    assert_eq!(
+        body.as_document(),
+        Some(&AdfDocument::from_plain("Lorem ipsum dolor sit amet."))
+    );
+    assert_eq!(serde_json::to_value(&body).unwrap(), document);
+}
+
+#[test]
+fn add_document_comment() {
+    let server = MockServer::start(vec![Response::json(
+        201,
+        &COMMENT.replace(
+            r#""Lorem ipsum dolor sit amet.""#,
+            r#"{"version": 1, "type": "doc", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Lorem ipsum dolor sit amet."}]}]}"#,
+        ),
+    )]);
+    let mut builder = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    );
+    builder.api_version(ApiVersion::V3);
+
+    let comment = builder
+        .build()
+        .unwrap()
+        .issues()
+        .comments("TEST-1")
+        .add(AddComment::new(AdfDocument::from_plain(
+            "Lorem ipsum dolor sit amet.",
+        )))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/rest/api/3/issue/TEST-1/comment");
+    assert_eq!(request.json()["body"]["type"], "doc");
+    assert_eq!(
+        request.json()["body"]["content"][0]["content"][0]["text"],
+        "Lorem ipsum dolor sit amet."
+    );
+    assert!(comment.body.as_document().is_some());
+}
+
+#[test]
+fn empty_plain_document_has_no_text_nodes() {
+    assert_eq!(
+        serde_json::to_value(AdfDocument::from_plain("")).unwrap(),
+        serde_json::json!({
+            "version": 1,
+            "type": "doc",
+            "content": [{ "type": "paragraph", "content": [] }]
+        })
+    );
+}
+
+#[test]
+fn update_comment() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        &COMMENT.replace("Lorem ipsum dolor sit amet.", "edited"),
+    )]);
+
+    let comment = jira(&server)
+        .issues()
+        .comments("TEST-1")
+        .update("10000", UpdateComment::new("edited"))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "PUT");
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/comment/10000");
+    // visibility is left out, so that jira keeps the existing restriction
+    assert_eq!(request.json(), serde_json::json!({ "body": "edited" }));
+    assert_eq!(comment.body.as_plain(), Some("edited"));
+    assert_eq!(comment.visibility.unwrap().value, "Administrators");
+}
+
+#[test]
+fn update_comment_visibility() {
+    let server = MockServer::start(vec![Response::json(200, COMMENT)]);
+
+    jira(&server)
+        .issues()
+        .comments("TEST-1")
+        .update(
+            "10000",
+            UpdateComment {
+                body: "Lorem ipsum dolor sit amet.".into(),
+                visibility: Some(Visibility {
+                    visibility_type: "group".to_owned(),
+                    value: "jira-developers".to_owned(),
+                }),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].json()["visibility"],
+        serde_json::json!({ "type": "group", "value": "jira-developers" })
+    );
+}
+
+#[test]
+fn delete_comment() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .comments("TEST-1")
+        .delete("10000")
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "DELETE");
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/comment/10000");
+}
@@ -0,0 +1,77 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+const COMPONENT: &str = r#"{
+    "self": "http://jira.com/rest/api/2/component/10000",
+    "id": "10000",
+    "name": "Component 1",
+    "description": "This is a Jira component",
+    "lead": {
+        "self": "http://jira.com/rest/api/2/user?username=fred",
+        "name": "fred",
+        "displayName": "Fred F. User",
+        "active": true
+    },
+    "project": "TEST",
+    "projectId": 10000
+}"#;
+
+#[test]
+fn list_project_components() {
+    let server = MockServer::start(vec![Response::json(200, &format!("[{}]", COMPONENT))]);
+
+    let components = jira(&server).projects().components("TEST").unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/project/TEST/components"
+    );
+    assert_eq!(components[0].id, Some("10000".to_owned()));
+    assert_eq!(components[0].name, "Component 1");
+    assert_eq!(
+        components[0].description,
+        Some("This is a Jira component".to_owned())
+    );
+    assert_eq!(
+        components[0].lead.as_ref().and_then(|lead| lead.name.clone()),
+        Some("fred".to_owned())
+    );
+}
+
+#[test]
+fn create_component() {
+    let server = MockServer::start(vec![Response::json(201, COMPONENT)]);
+
+    let mut data = CreateComponent::new("Component 1", "TEST");
+    data.lead_user_name = Some("fred".to_owned());
+    let component = jira(&server).components().create(data).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/component");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "name": "Component 1",
+            "project": "TEST",
+            "leadUserName": "fred"
+        })
+    );
+    assert_eq!(component.id, Some("10000".to_owned()));
+}
+
+#[test]
+fn refer_to_components_by_name_or_id() {
+    let components = vec![Component::new("Component 1"), Component::with_id("10001")];
+
+    assert_eq!(
+        serde_json::to_value(&components).unwrap(),
+        serde_json::json!([{ "name": "Component 1" }, { "id": "10001" }])
+    );
+}
@@ -0,0 +1,77 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+const DASHBOARD: &str = r#"{
+    "id": "10000",
+    "name": "System Dashboard",
+    "self": "http://jira.com/rest/api/2/dashboard/10000",
+    "view": "http://jira.com/secure/Dashboard.jspa?selectPageId=10000",
+    "sharePermissions": [{"type": "global"}]
+}"#;
+
+#[test]
+fn list_dashboards() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        &format!(
+            r#"{{
+                "startAt": 0,
+                "maxResults": 10,
+                "total": 2,
+                "prev": null,
+                "next": null,
+                "dashboards": [{}, {{
+                    "id": "10100",
+                    "name": "Team Dashboard",
+                    "self": "http://jira.com/rest/api/2/dashboard/10100",
+                    "view": "http://jira.com/secure/Dashboard.jspa?selectPageId=10100",
+                    "sharePermissions": [{{
+                        "id": 10050,
+                        "type": "group",
+                        "group": {{"name": "jira-developers"}}
+                    }}]
+                }}]
+            }}"#,
+            DASHBOARD
+        ),
+    )]);
+
+    let page = jira(&server)
+        .dashboards()
+        .list(&SearchOptions::builder().max_results(10).build())
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/api/latest/dashboard");
+    assert_eq!(request.query("maxResults"), Some("10".to_owned()));
+    assert_eq!(page.total, 2);
+    assert_eq!(page.values.len(), 2);
+    assert_eq!(page.values[0].name, "System Dashboard");
+    assert_eq!(page.values[0].share_permissions[0].share_type, "global");
+    let share = &page.values[1].share_permissions[0];
+    assert_eq!(share.share_type, "group");
+    assert_eq!(share.group.as_ref().unwrap().name, "jira-developers");
+}
+
+#[test]
+fn get_dashboard() {
+    let server = MockServer::start(vec![Response::json(200, DASHBOARD)]);
+
+    let dashboard = jira(&server).dashboards().get("10000").unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/dashboard/10000"
+    );
+    assert_eq!(dashboard.id, "10000");
+    assert_eq!(
+        dashboard.view,
+        Some("http://jira.com/secure/Dashboard.jspa?selectPageId=10000".to_owned())
+    );
+}
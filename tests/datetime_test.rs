@@ -0,0 +1,149 @@
+#![cfg(feature = "chrono")]
+
+extern crate chrono;
+extern crate jira_rs;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+
+use chrono::{Datelike, FixedOffset, Timelike};
+use jira_rs::datetime;
+use jira_rs::{Comment, Fields, History, Worklog};
+
+#[test]
+fn retains_offset() {
+    let parsed = datetime::parse("2015-04-11T15:22:00.000+1000").unwrap();
+
+    assert_eq!(parsed.offset(), &FixedOffset::east_opt(10 * 3600).unwrap());
+    assert_eq!(parsed.day(), 11);
+    assert_eq!(parsed.hour(), 15);
+}
+
+#[test]
+fn parses_negative_offsets() {
+    let parsed = datetime::parse("2019-12-31T23:59:59.999-0530").unwrap();
+
+    assert_eq!(
+        parsed.offset(),
+        &FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap()
+    );
+    assert_eq!(parsed.year(), 2019);
+}
+
+#[test]
+fn rejects_invalid_timestamps() {
+    assert!(datetime::parse("yesterday").is_none());
+}
+
+#[test]
+fn comment_timestamps() {
+    let comment: Comment = serde_json::from_str(
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/10010/comment/10000",
+            "id": "10000",
+            "body": "a comment",
+            "created": "2015-04-11T15:22:00.000+1000",
+            "updated": "2015-04-12T09:00:00.000+0000"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        comment.created_at().unwrap().offset(),
+        &FixedOffset::east_opt(10 * 3600).unwrap()
+    );
+    assert_eq!(comment.updated_at().unwrap().hour(), 9);
+}
+
+#[test]
+fn rejects_comments_with_invalid_timestamps() {
+    let comment = serde_json::from_str::<Comment>(
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/10010/comment/10000",
+            "body": "a comment",
+            "created": "yesterday",
+            "updated": "2015-04-12T09:00:00.000+0000"
+        }"#,
+    );
+
+    assert!(comment.is_err());
+}
+
+#[test]
+fn worklog_and_history_timestamps() {
+    let worklog: Worklog = serde_json::from_str(
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/10010/worklog/10000",
+            "id": "10000",
+            "started": "2015-04-11T15:22:00.000-0700",
+            "created": "2015-04-11T16:00:00.000-0700",
+            "updated": null,
+            "timeSpentSeconds": 3600
+        }"#,
+    )
+    .unwrap();
+    let history: History = serde_json::from_str(
+        r#"{
+            "id": "10000",
+            "author": {"self": "http://jira.com/rest/api/2/user?username=fred", "name": "fred", "displayName": "Fred", "active": true},
+            "created": "2015-04-12T09:00:00.000+0530",
+            "items": []
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(worklog.started, "2015-04-11T15:22:00.000-0700");
+    assert_eq!(
+        worklog.started_at().unwrap().offset(),
+        &FixedOffset::west_opt(7 * 3600).unwrap()
+    );
+    assert_eq!(worklog.created_at().unwrap().hour(), 16);
+    assert!(worklog.updated_at().is_none());
+    assert_eq!(
+        history.created_at().unwrap().offset(),
+        &FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
+    );
+}
+
+#[test]
+fn fields_timestamps() {
+    let fields: Fields = serde_json::from_str(
+        r#"{
+            "issuetype": {"id": "10004"},
+            "project": {"key": "TEST"},
+            "summary": "Something is broken",
+            "created": "2018-07-11T16:56:12.000+1000",
+            "updated": "2018-07-12T08:00:00.000+0000"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        fields.created_at().unwrap().offset(),
+        &FixedOffset::east_opt(10 * 3600).unwrap()
+    );
+    assert_eq!(fields.updated_at().unwrap().hour(), 8);
+    assert!(serde_json::from_str::<Fields>(
+        r#"{"issuetype": {"id": "10004"}, "project": {"key": "TEST"}, "summary": "", "created": "nope"}"#
+    )
+    .is_err());
+}
+
+#[derive(Deserialize)]
+struct Stamped {
+    #[serde(deserialize_with = "datetime::deserialize")]
+    at: chrono::DateTime<FixedOffset>,
+    #[serde(default, deserialize_with = "datetime::deserialize_option")]
+    maybe: Option<chrono::DateTime<FixedOffset>>,
+}
+
+#[test]
+fn deserialize_with() {
+    let stamped: Stamped =
+        serde_json::from_str(r#"{"at": "2015-04-11T15:22:00.000+1000", "maybe": null}"#).unwrap();
+
+    assert_eq!(stamped.at.minute(), 22);
+    assert!(stamped.maybe.is_none());
+    assert!(serde_json::from_str::<Stamped>(r#"{"at": "nope"}"#).is_err());
+}
@@ -0,0 +1,81 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+#[test]
+fn get_epic() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "id": 37,
+            "key": "EPIC-1",
+            "self": "http://jira.com/rest/agile/1.0/epic/37",
+            "name": "epic 1",
+            "summary": "epic 1 summary",
+            "color": {"key": "color_4"},
+            "done": true
+        }"#,
+    )]);
+
+    let epic = jira(&server).epics().get("EPIC-1").unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/agile/latest/epic/EPIC-1");
+    assert_eq!(epic.id, 37);
+    assert_eq!(epic.name, "epic 1");
+    assert!(epic.done);
+}
+
+#[test]
+fn list_epic_issues() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"startAt": 0, "maxResults": 1, "total": 2, "issues": [
+            {"self": "http://jira.com/rest/api/2/issue/10001", "id": "10001", "key": "TEST-2", "fields": {}}
+        ]}"#,
+    )]);
+
+    let issues = jira(&server)
+        .epics()
+        .issues(37, &SearchOptions::builder().max_results(1).build())
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/agile/latest/epic/37/issue");
+    assert_eq!(request.query("maxResults"), Some("1".to_owned()));
+    assert_eq!(issues.total, 2);
+    assert_eq!(issues.values[0].key, "TEST-2");
+}
+
+#[test]
+fn move_issue_into_epic() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .epics()
+        .move_issues(37, vec!["TEST-2".to_owned()])
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/agile/latest/epic/37/issue");
+    assert_eq!(request.json(), serde_json::json!({ "issues": ["TEST-2"] }));
+}
+
+#[test]
+fn remove_issue_from_epic() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .epics()
+        .remove_issues(vec!["TEST-2".to_owned()])
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/rest/agile/latest/epic/none/issue");
+    assert_eq!(request.json(), serde_json::json!({ "issues": ["TEST-2"] }));
+}
@@ -0,0 +1,178 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::issues::*;
+use jira_rs::Error;
+use support::{jira, MockServer, Response};
+
+fn create() -> CreateIssue {
+    CreateIssue {
+        fields: Fields {
+            project: Project {
+                key: "NOPE".to_owned(),
+            },
+            summary: "summary".to_owned(),
+            ..Default::default()
+        },
+    }
+}
+
+#[test]
+fn field_errors_are_extracted() {
+    let server = MockServer::start(vec![Response::json(
+        400,
+        r#"{
+            "errorMessages": [],
+            "errors": {
+                "project": "project is required",
+                "issuetype": "issue type is required"
+            }
+        }"#,
+    )]);
+
+    match jira(&server).issues().create(create()) {
+        Err(Error::Fault { code, errors }) => {
+            assert_eq!(code.as_u16(), 400);
+            assert!(errors.error_messages.is_empty());
+            assert_eq!(errors.errors.len(), 2);
+            assert_eq!(
+                errors.errors.get("project").map(String::as_str),
+                Some("project is required")
+            );
+        }
+        other => panic!("expected a fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn error_messages_without_field_errors() {
+    let server = MockServer::start(vec![Response::json(
+        400,
+        r#"{"errorMessages": ["Field 'foo' does not exist or you do not have permission to view it."]}"#,
+    )]);
+
+    match jira(&server).issues().create(create()) {
+        Err(Error::Fault { errors, .. }) => {
+            assert_eq!(errors.error_messages.len(), 1);
+            assert!(errors.errors.is_empty());
+        }
+        other => panic!("expected a fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn server_errors_are_faults() {
+    let server = MockServer::start(vec![Response::new(502).body(b"Bad Gateway".to_vec())]);
+
+    match jira(&server).issues().create(create()) {
+        Err(Error::Fault { code, errors }) => {
+            assert_eq!(code.as_u16(), 502);
+            assert_eq!(errors.error_messages, vec!["Bad Gateway".to_owned()]);
+        }
+        other => panic!("expected a fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn bad_credentials_are_unauthorized() {
+    let server = MockServer::start(vec![Response::new(401)]);
+
+    match jira(&server).myself() {
+        Err(Error::Unauthorized { errors }) => assert!(errors.error_messages.is_empty()),
+        other => panic!("expected unauthorized, got {:?}", other),
+    }
+}
+
+#[test]
+fn unauthorized_responses_carry_reported_errors() {
+    let server = MockServer::start(vec![Response::json(
+        401,
+        r#"{"errorMessages": ["You are not authenticated. Authentication required to perform this operation."], "errors": {}}"#,
+    )]);
+
+    match jira(&server).myself() {
+        Err(Error::Unauthorized { errors }) => assert_eq!(
+            errors.error_messages,
+            vec!["You are not authenticated. Authentication required to perform this operation."]
+        ),
+        other => panic!("expected unauthorized, got {:?}", other),
+    }
+}
+
+#[test]
+fn missing_issues_are_not_found() {
+    let server = MockServer::start(vec![Response::json(
+        404,
+        r#"{"errorMessages": ["Issue does not exist or you do not have permission to see it."], "errors": {}}"#,
+    )]);
+
+    match jira(&server).issues().get("TEST-404") {
+        Err(Error::NotFound { errors }) => assert_eq!(
+            errors.error_messages,
+            vec!["Issue does not exist or you do not have permission to see it."]
+        ),
+        other => panic!("expected not found, got {:?}", other),
+    }
+}
+
+#[test]
+fn disallowed_methods_carry_reported_errors() {
+    let server = MockServer::start(vec![Response::new(405).body(b"Method Not Allowed".to_vec())]);
+
+    match jira(&server).issues().get("TEST-1") {
+        Err(Error::MethodNotAllowed { errors }) => {
+            assert_eq!(errors.error_messages, vec!["Method Not Allowed".to_owned()])
+        }
+        other => panic!("expected method not allowed, got {:?}", other),
+    }
+}
+
+#[test]
+fn missing_permissions_are_forbidden() {
+    let server = MockServer::start(vec![Response::json(
+        403,
+        r#"{"errorMessages": ["You do not have permission to create issues in this project."], "errors": {}}"#,
+    )]);
+
+    match jira(&server).issues().create(create()) {
+        Err(Error::Forbidden { errors }) => assert_eq!(
+            errors.error_messages,
+            vec!["You do not have permission to create issues in this project."]
+        ),
+        other => panic!("expected forbidden, got {:?}", other),
+    }
+}
+
+#[test]
+fn html_responses_are_unexpected_content() {
+    let login = format!(
+        "<!DOCTYPE html><html><head><title>Log in</title></head><body>{}</body></html>",
+        "<p>log in to continue</p>".repeat(50)
+    );
+    let server = MockServer::start(vec![Response::new(200)
+        .header("Content-Type", "text/html;charset=UTF-8")
+        .body(login.into_bytes())]);
+
+    let result = jira(&server).issues().get("TEST-1");
+
+    assert_eq!(
+        server.requests()[0].header("Accept"),
+        Some("application/json")
+    );
+    match result {
+        Err(Error::UnexpectedContentType {
+            content_type,
+            status,
+            body_snippet,
+        }) => {
+            assert_eq!(content_type, "text/html;charset=UTF-8");
+            assert_eq!(status.as_u16(), 200);
+            assert!(body_snippet.starts_with("<!DOCTYPE html>"));
+            assert_eq!(body_snippet.chars().count(), 200);
+        }
+        other => panic!("expected unexpected content, got {:?}", other),
+    }
+}
@@ -0,0 +1,104 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+const FILTER: &str = r#"{
+    "self": "http://jira.com/rest/api/2/filter/10000",
+    "id": "10000",
+    "name": "All Open Bugs",
+    "description": "Lists all open bugs",
+    "owner": {
+        "self": "http://jira.com/rest/api/2/user?username=fred",
+        "name": "fred",
+        "displayName": "Fred F. User",
+        "active": false
+    },
+    "jql": "type = Bug and resolution is empty",
+    "viewUrl": "http://jira.com/issues/?filter=10000",
+    "searchUrl": "http://jira.com/rest/api/2/search?jql=type%20%3D%20Bug%20and%20resolution%20is%20empty",
+    "favourite": true
+}"#;
+
+#[test]
+fn get_filter() {
+    let server = MockServer::start(vec![Response::json(200, FILTER)]);
+
+    let filter = jira(&server).filters().get(10000).unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/filter/10000");
+    assert_eq!(filter.name, "All Open Bugs");
+    assert_eq!(filter.jql, "type = Bug and resolution is empty");
+    assert_eq!(filter.owner.unwrap().display_name, "Fred F. User");
+    assert!(filter.search_url.unwrap().contains("/search?jql="));
+    assert!(filter.favourite);
+}
+
+#[test]
+fn favourite_filters() {
+    let server = MockServer::start(vec![Response::json(200, &format!("[{}]", FILTER))]);
+
+    let filters = jira(&server).filters().favourites().unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/filter/favourite"
+    );
+    assert_eq!(filters[0].id, "10000");
+}
+
+#[test]
+fn create_filter() {
+    let server = MockServer::start(vec![Response::json(200, FILTER)]);
+
+    jira(&server)
+        .filters()
+        .create(CreateFilter::new(
+            "All Open Bugs",
+            "type = Bug and resolution is empty",
+        ))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/filter");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "name": "All Open Bugs",
+            "jql": "type = Bug and resolution is empty"
+        })
+    );
+}
+
+#[test]
+fn search_with_filter() {
+    let server = MockServer::start(vec![
+        Response::json(200, FILTER),
+        Response::json(
+            200,
+            r#"{"startAt": 0, "maxResults": 50, "total": 1, "issues": [
+                {"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}
+            ]}"#,
+        ),
+    ]);
+    let jira = jira(&server);
+
+    let filter = jira.filters().get(10000).unwrap();
+    let results = jira
+        .issues()
+        .search_filter(&filter, &Default::default())
+        .unwrap();
+
+    let request = &server.requests()[1];
+    assert_eq!(request.path_only(), "/rest/api/latest/search");
+    assert_eq!(
+        request.query("jql"),
+        Some("type = Bug and resolution is empty".to_owned())
+    );
+    assert_eq!(results.values[0].key, "TEST-1");
+}
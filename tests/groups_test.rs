@@ -0,0 +1,127 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::SearchOptions;
+use support::{jira, MockServer, Response};
+
+fn member(name: &str) -> String {
+    format!(
+        r#"{{
+            "self": "http://jira.com/rest/api/2/user?username={0}",
+            "name": "{0}",
+            "key": "{0}",
+            "displayName": "{0}",
+            "active": true
+        }}"#,
+        name
+    )
+}
+
+#[test]
+fn members_across_pages() {
+    let server = MockServer::start(vec![
+        Response::json(
+            200,
+            &format!(
+                r#"{{"startAt": 0, "maxResults": 2, "total": 3, "isLast": false, "values": [{}, {}]}}"#,
+                member("fred"),
+                member("wilma")
+            ),
+        ),
+        Response::json(
+            200,
+            &format!(
+                r#"{{"startAt": 2, "maxResults": 2, "total": 3, "isLast": true, "values": [{}]}}"#,
+                member("barney")
+            ),
+        ),
+    ]);
+    let groups = jira(&server).groups();
+
+    let first = groups
+        .members(
+            "jira developers",
+            &SearchOptions::builder().max_results(2).build(),
+        )
+        .unwrap();
+    let second = groups
+        .members(
+            "jira developers",
+            &SearchOptions::builder().start_at(2).max_results(2).build(),
+        )
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path_only(), "/rest/api/latest/group/member");
+    assert_eq!(
+        requests[0].query("groupname"),
+        Some("jira developers".to_owned())
+    );
+    assert_eq!(requests[1].query("startAt"), Some("2".to_owned()));
+    assert_eq!(first.is_last, Some(false));
+    assert_eq!(second.is_last, Some(true));
+    let names = first
+        .values
+        .iter()
+        .chain(second.values.iter())
+        .map(|user| user.display_name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["fred", "wilma", "barney"]);
+}
+
+#[test]
+fn add_user_to_group() {
+    let server = MockServer::start(vec![
+        Response::json(
+            201,
+            r#"{"name": "jira-developers", "self": "http://jira.com/rest/api/2/group?groupname=jira-developers"}"#,
+        ),
+        Response::json(201, r#"{"name": "jira-developers"}"#),
+    ]);
+    let groups = jira(&server).groups();
+
+    groups
+        .add_user("jira-developers", "5b10a2844c20165700ede21g")
+        .unwrap();
+    groups
+        .add_user_by_username("jira-developers", "fred")
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path_only(), "/rest/api/latest/group/user");
+    assert_eq!(
+        requests[0].query("groupname"),
+        Some("jira-developers".to_owned())
+    );
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({ "accountId": "5b10a2844c20165700ede21g" })
+    );
+    assert_eq!(requests[1].json(), serde_json::json!({ "name": "fred" }));
+}
+
+#[test]
+fn remove_user_from_group() {
+    let server = MockServer::start(vec![Response::new(200), Response::new(200)]);
+    let groups = jira(&server).groups();
+
+    groups
+        .remove_user("jira-developers", "5b10a2844c20165700ede21g")
+        .unwrap();
+    groups
+        .remove_user_by_username("jira-developers", "fred")
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "DELETE");
+    assert_eq!(requests[0].path_only(), "/rest/api/latest/group/user");
+    assert_eq!(
+        requests[0].query("accountId"),
+        Some("5b10a2844c20165700ede21g".to_owned())
+    );
+    assert_eq!(requests[1].query("username"), Some("fred".to_owned()));
+}
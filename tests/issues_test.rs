@@ -1,7 +1,16 @@
-extern crate goji;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+extern crate jira_rs;
 extern crate serde_json;
+extern crate url;
 
-use goji::issues::*;
+mod support;
+
+use jira_rs::issues::*;
+use jira_rs::{
+    AddComment, Credentials, Deployment, Error, Issue, Jira, SearchOptions, ValidateQuery, Version,
+};
+use support::{board, issues_page, jira, MockServer, Response};
 
 #[test]
 fn deserialise_issue_results() {
@@ -13,11 +22,2066 @@ fn deserialise_issue_results() {
         "issues": []
     }"#;
 
-    let results: IssueResults = serde_json::from_str(issue_results_str).unwrap();
+    let results: Paginated<Issue> = serde_json::from_str(issue_results_str).unwrap();
 
-    assert_eq!(results.expand, "names,schema");
+    assert_eq!(results.expand, Some("names,schema".to_owned()));
     assert_eq!(results.start_at, 0);
     assert_eq!(results.max_results, 50);
     assert_eq!(results.total, 0);
-    assert_eq!(results.issues.len(), 0);
+    assert_eq!(results.values.len(), 0);
+}
+
+#[test]
+fn iter_preserves_server_order() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 4, &[1, 2]),
+        issues_page(2, 2, 4, &[3, 4]),
+    ]);
+    let board = board();
+    let options = Default::default();
+
+    let ids = jira(&server)
+        .issues()
+        .iter(&board, &options)
+        .map(|issue| issue.unwrap().id)
+        .collect::<Vec<String>>();
+
+    assert_eq!(ids, vec!["1", "2", "3", "4"]);
+}
+
+#[test]
+fn iter_stops_when_total_is_a_multiple_of_page_size() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 4, &[1, 2]),
+        issues_page(2, 2, 4, &[3, 4]),
+        issues_page(4, 2, 4, &[]),
+    ]);
+    let board = board();
+    let options = Default::default();
+
+    let count = jira(&server)
+        .issues()
+        .iter(&board, &options)
+        .count();
+
+    assert_eq!(count, 4);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn iter_advances_by_issues_returned() {
+    // the server caps the first page below the requested page size
+    let server = MockServer::start(vec![
+        issues_page(0, 3, 5, &[1, 2]),
+        issues_page(2, 3, 5, &[3, 4, 5]),
+    ]);
+    let board = board();
+    let options = Default::default();
+
+    let ids = jira(&server)
+        .issues()
+        .iter(&board, &options)
+        .map(|issue| issue.unwrap().id)
+        .collect::<Vec<String>>();
+
+    assert_eq!(ids, vec!["1", "2", "3", "4", "5"]);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].query("startAt"), Some("2".to_owned()));
+}
+
+#[test]
+fn update_sends_only_set_fields() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .update(
+            "TEST-1",
+            UpdateIssue {
+                fields: UpdateFields {
+                    summary: Some("new summary".to_owned()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "PUT");
+    assert_eq!(requests[0].path, "/rest/api/latest/issue/TEST-1");
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({ "fields": { "summary": "new summary" } })
+    );
+}
+
+#[test]
+fn update_surfaces_field_errors() {
+    let server = MockServer::start(vec![Response::json(
+        400,
+        r#"{"errorMessages": [], "errors": {"summary": "Field 'summary' cannot be set."}}"#,
+    )]);
+
+    let result = jira(&server).issues().update("TEST-1", Default::default());
+
+    match result {
+        Err(jira_rs::Error::Fault { code, errors }) => {
+            assert_eq!(code.as_u16(), 400);
+            assert_eq!(
+                errors.errors.get("summary").map(String::as_str),
+                Some("Field 'summary' cannot be set.")
+            );
+        }
+        other => panic!("expected a fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn delete_issue() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server).issues().delete("TEST-1", true).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "DELETE");
+    assert_eq!(requests[0].path_only(), "/rest/api/latest/issue/TEST-1");
+    assert_eq!(requests[0].query("deleteSubtasks"), Some("true".to_owned()));
+}
+
+#[test]
+fn delete_issue_with_subtasks() {
+    let server = MockServer::start(vec![Response::json(
+        400,
+        r#"{"errorMessages": ["The issue 'TEST-1' has subtasks.  You must specify the 'deleteSubtasks' parameter to delete this issue with subtasks."], "errors": {}}"#,
+    )]);
+
+    match jira(&server).issues().delete("TEST-1", false) {
+        Err(jira_rs::Error::Fault { code, errors }) => {
+            assert_eq!(code.as_u16(), 400);
+            assert!(errors.error_messages[0].contains("has subtasks"));
+        }
+        other => panic!("expected a fault, got {:?}", other),
+    }
+    assert_eq!(
+        server.requests()[0].query("deleteSubtasks"),
+        Some("false".to_owned())
+    );
+}
+
+#[test]
+fn assign_issue() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .assign("TEST-1", Some(Assignee::by_name("doug")))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "PUT");
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/assignee");
+    assert_eq!(request.json(), serde_json::json!({ "name": "doug" }));
+}
+
+#[test]
+fn unassign_issue_on_server() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server).issues().assign("TEST-1", None).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "PUT");
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/assignee");
+    assert_eq!(request.json(), serde_json::json!({ "name": null }));
+}
+
+#[test]
+fn unassign_issue_on_cloud() {
+    let server = MockServer::start(vec![Response::new(204)]);
+    let cloud = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .deployment(Deployment::Cloud)
+    .build()
+    .unwrap();
+
+    cloud.issues().assign("TEST-1", None).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/assignee");
+    assert_eq!(request.json(), serde_json::json!({ "accountId": null }));
+}
+
+#[test]
+fn search_encodes_jql() {
+    let server = MockServer::start(vec![issues_page(0, 50, 1, &[1])]);
+    let jql = r#"project = "MY PROJ" AND summary ~ 'it''s broken'"#;
+
+    let results = jira(&server)
+        .issues()
+        .search(
+            jql,
+            &SearchOptions::builder()
+                .fields(vec!["summary", "status"])
+                .expand(vec!["changelog"])
+                .start_at(0)
+                .max_results(50)
+                .build(),
+        )
+        .unwrap();
+
+    assert_eq!(results.values[0].key, "TEST-1");
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/api/latest/search");
+    assert!(!request.path.contains(' '));
+    assert_eq!(request.query("jql"), Some(jql.to_owned()));
+    assert_eq!(request.query("fields"), Some("summary,status".to_owned()));
+    assert_eq!(request.query("expand"), Some("changelog".to_owned()));
+    assert_eq!(request.query("startAt"), Some("0".to_owned()));
+    assert_eq!(request.query("maxResults"), Some("50".to_owned()));
+}
+
+#[test]
+fn count_matching_issues() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"startAt": 0, "maxResults": 0, "total": 1234, "issues": []}"#,
+    )]);
+
+    let count = jira(&server)
+        .issues()
+        .count("project = TEST AND resolution IS EMPTY")
+        .unwrap();
+
+    assert_eq!(count, 1234);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path_only(), "/rest/api/latest/search");
+    assert_eq!(
+        requests[0].query("jql"),
+        Some("project = TEST AND resolution IS EMPTY".to_owned())
+    );
+    assert_eq!(requests[0].query("maxResults"), Some("0".to_owned()));
+    assert_eq!(requests[0].query("fields"), Some("".to_owned()));
+}
+
+#[test]
+fn search_iter_pages_through_results() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 3, &[1, 2]),
+        issues_page(2, 2, 3, &[3]),
+    ]);
+    let options = Default::default();
+
+    let ids = jira(&server)
+        .issues()
+        .search_iter("project = TEST", &options)
+        .map(|issue| issue.unwrap().id)
+        .collect::<Vec<String>>();
+
+    assert_eq!(ids, vec!["1", "2", "3"]);
+    let requests = server.requests();
+    assert_eq!(requests[1].query("jql"), Some("project = TEST".to_owned()));
+    assert_eq!(requests[1].query("startAt"), Some("2".to_owned()));
+}
+
+#[test]
+fn iter_reports_progress() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 3, &[1, 2]),
+        issues_page(2, 2, 3, &[3]),
+    ]);
+    let options = Default::default();
+    let issues = jira(&server).issues();
+    let mut iter = issues.search_iter("project = TEST", &options);
+
+    assert_eq!(iter.total(), 0);
+    assert!(!iter.is_last_page());
+    iter.next().unwrap().unwrap();
+    assert_eq!(iter.total(), 3);
+    assert_eq!(iter.fetched_so_far(), 2);
+    assert!(!iter.is_last_page());
+    iter.next().unwrap().unwrap();
+    iter.next().unwrap().unwrap();
+    assert_eq!(iter.fetched_so_far(), 3);
+    assert!(iter.is_last_page());
+}
+
+#[test]
+fn search_all_collects_every_page() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 3, &[1, 2]),
+        issues_page(2, 2, 3, &[3]),
+    ]);
+
+    let issues = jira(&server)
+        .issues()
+        .search_all("project = TEST", &Default::default())
+        .unwrap();
+
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[2].key, "TEST-3");
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn search_updated_since_restricts_jql_and_expands_changelog() {
+    use chrono::{TimeZone, Utc};
+
+    let server = MockServer::start(vec![
+        issues_page(0, 50, 1, &[1]),
+        issues_page(0, 50, 1, &[1]),
+    ]);
+    let since = Utc.with_ymd_and_hms(2023, 1, 31, 22, 5, 59).unwrap();
+    let issues = jira(&server).issues();
+
+    issues
+        .search_updated_since(
+            "project = TEST ORDER BY updated ASC",
+            since,
+            &Utc,
+            &SearchOptions::builder().expand(vec!["names"]).build(),
+        )
+        .unwrap();
+    issues
+        .search_updated_since("", since, &Utc, &Default::default())
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].query("jql"),
+        Some(
+            r#"(project = TEST) AND updated >= "2023/01/31 22:05" ORDER BY updated ASC"#.to_owned()
+        )
+    );
+    assert_eq!(
+        requests[0].query("expand"),
+        Some("names,changelog".to_owned())
+    );
+    assert_eq!(
+        requests[1].query("jql"),
+        Some(r#"updated >= "2023/01/31 22:05""#.to_owned())
+    );
+    assert_eq!(requests[1].query("expand"), Some("changelog".to_owned()));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn search_updated_since_converts_to_the_users_zone() {
+    use chrono::{FixedOffset, TimeZone, Utc};
+
+    let server = MockServer::start(vec![
+        issues_page(0, 50, 1, &[1]),
+        issues_page(0, 50, 1, &[1]),
+    ]);
+    let since = Utc.with_ymd_and_hms(2023, 1, 31, 22, 5, 59).unwrap();
+    let issues = jira(&server).issues();
+
+    issues
+        .search_updated_since(
+            "",
+            since,
+            &FixedOffset::east_opt(10 * 3600).unwrap(),
+            &Default::default(),
+        )
+        .unwrap();
+    issues
+        .search_updated_since(
+            "",
+            since.with_timezone(&FixedOffset::east_opt(10 * 3600).unwrap()),
+            &FixedOffset::west_opt(5 * 3600).unwrap(),
+            &Default::default(),
+        )
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].query("jql"),
+        Some(r#"updated >= "2023/02/01 08:05""#.to_owned())
+    );
+    assert_eq!(
+        requests[1].query("jql"),
+        Some(r#"updated >= "2023/01/31 17:05""#.to_owned())
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn search_updated_since_ignores_quoted_order_by() {
+    use chrono::{TimeZone, Utc};
+
+    let server = MockServer::start(vec![
+        issues_page(0, 50, 1, &[1]),
+        issues_page(0, 50, 1, &[1]),
+    ]);
+    let since = Utc.with_ymd_and_hms(2023, 1, 31, 22, 5, 59).unwrap();
+    let issues = jira(&server).issues();
+
+    issues
+        .search_updated_since(
+            r#"summary ~ "order by" ORDER BY key"#,
+            since,
+            &Utc,
+            &Default::default(),
+        )
+        .unwrap();
+    issues
+        .search_updated_since(
+            r#"summary ~ 'it\'s order by' OR summary ~ "\"order by\"""#,
+            since,
+            &Utc,
+            &Default::default(),
+        )
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].query("jql"),
+        Some(r#"(summary ~ "order by") AND updated >= "2023/01/31 22:05" ORDER BY key"#.to_owned())
+    );
+    assert_eq!(
+        requests[1].query("jql"),
+        Some(
+            r#"(summary ~ 'it\'s order by' OR summary ~ "\"order by\"") AND updated >= "2023/01/31 22:05""#
+                .to_owned()
+        )
+    );
+}
+
+#[test]
+fn list_all_stops_at_the_first_error() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 4, &[1, 2]),
+        Response::json(500, r#"{"errorMessages": ["boom"]}"#),
+    ]);
+
+    let result = jira(&server).issues().list_all(&board(), &Default::default());
+
+    assert!(result.is_err());
+    assert_eq!(server.requests()[1].path_only(), "/rest/agile/latest/board/1/issue");
+}
+
+#[test]
+fn deserialise_fields_without_assignee_or_description() {
+    let fields_str = r#"{
+        "issuetype": {
+            "self": "https://jira.com/rest/api/2/issuetype/10004",
+            "id": "10004",
+            "description": "A problem which impairs or prevents the functions of the product.",
+            "iconUrl": "https://jira.com/images/icons/issuetypes/bug.svg",
+            "name": "Bug",
+            "subtask": false
+        },
+        "project": {
+            "self": "https://jira.com/rest/api/2/project/10000",
+            "id": "10000",
+            "key": "TEST",
+            "name": "Test Project"
+        },
+        "resolution": null,
+        "assignee": null,
+        "description": null,
+        "environment": null,
+        "reporter": {
+            "self": "https://jira.com/rest/api/2/user?username=doug",
+            "name": "doug",
+            "displayName": "Doug",
+            "active": true
+        },
+        "summary": "Something is broken",
+        "created": "2018-07-11T16:56:12.000+0000",
+        "labels": []
+    }"#;
+
+    let fields: Fields = serde_json::from_str(fields_str).unwrap();
+
+    assert_eq!(fields.summary, "Something is broken");
+    assert_eq!(fields.project.key, "TEST");
+    assert_eq!(fields.issuetype.id, "10004");
+    assert!(fields.assignee.is_none());
+    assert!(fields.description.is_none());
+    assert!(fields.priority.is_none());
+    assert!(fields.components.is_empty());
+    assert_eq!(fields.reporter.map(|r| r.name), Some(Some("doug".to_owned())));
+}
+
+#[test]
+fn fields_custom_fields() {
+    let fields: Fields = serde_json::from_str(
+        r#"{
+            "issuetype": {"id": "10004"},
+            "project": {"key": "TEST"},
+            "summary": "Something is broken",
+            "customfield_10010": "team a",
+            "customfield_10016": 5.0
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        fields
+            .custom_field::<String>("customfield_10010")
+            .map(|value| value.unwrap()),
+        Some("team a".to_owned())
+    );
+    assert_eq!(
+        fields
+            .custom_field::<f64>("customfield_10016")
+            .map(|value| value.unwrap()),
+        Some(5.0)
+    );
+    assert!(fields.custom_field::<String>("customfield_10099").is_none());
+    assert!(fields
+        .custom_field::<u64>("customfield_10010")
+        .unwrap()
+        .is_err());
+}
+
+#[test]
+fn custom_fields_serialize_alongside_fields() {
+    let mut fields = Fields {
+        summary: "Something is broken".to_owned(),
+        ..Default::default()
+    };
+    fields
+        .custom
+        .insert("customfield_10016".to_owned(), serde_json::json!(5));
+
+    let value = serde_json::to_value(&fields).unwrap();
+
+    assert_eq!(value["customfield_10016"], serde_json::json!(5));
+    assert_eq!(value["summary"], serde_json::json!("Something is broken"));
+}
+
+#[test]
+fn link_issues() {
+    let server = MockServer::start(vec![Response::new(201)]);
+
+    let mut link = LinkIssues::new("Blocks", "TEST-1", "TEST-2");
+    link.comment = Some(AddComment::new("linked"));
+    jira(&server).issues().link(link).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/issueLink");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "type": { "name": "Blocks" },
+            "inwardIssue": { "key": "TEST-1" },
+            "outwardIssue": { "key": "TEST-2" },
+            "comment": { "body": "linked" }
+        })
+    );
+}
+
+#[test]
+fn list_link_types() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"issueLinkTypes": [{
+            "id": "10000",
+            "name": "Blocks",
+            "inward": "is blocked by",
+            "outward": "blocks",
+            "self": "http://jira.com/rest/api/2/issueLinkType/10000"
+        }]}"#,
+    )]);
+
+    let types = jira(&server).issues().link_types().unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/issueLinkType");
+    assert_eq!(types[0].name, "Blocks");
+    assert_eq!(types[0].inward, "is blocked by");
+}
+
+#[test]
+fn add_attachment() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"[{
+            "self": "http://jira.com/rest/api/2/attachment/10000",
+            "id": "10000",
+            "filename": "notes.txt",
+            "author": {
+                "self": "http://jira.com/rest/api/2/user?username=fred",
+                "name": "fred",
+                "displayName": "Fred F. User",
+                "active": true
+            },
+            "created": "2019-01-01T00:00:00.000+0000",
+            "size": 11,
+            "mimeType": "text/plain",
+            "content": "http://jira.com/secure/attachment/10000/notes.txt"
+        }]"#,
+    )]);
+
+    let attachments = jira(&server)
+        .issues()
+        .add_attachment("TEST-1", "notes.txt", b"hello jira!".to_vec())
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/attachments");
+    assert_eq!(request.header("X-Atlassian-Token"), Some("no-check"));
+    assert!(request
+        .header("Content-Type")
+        .unwrap()
+        .starts_with("multipart/form-data"));
+    let body = request.body_str();
+    assert!(body.contains(r#"name="file"; filename="notes.txt""#));
+    assert!(body.contains("hello jira!"));
+    assert_eq!(attachments[0].filename, "notes.txt");
+    assert_eq!(attachments[0].size, 11);
+    assert_eq!(attachments[0].mime_type, "text/plain");
+    assert_eq!(
+        attachments[0].content,
+        "http://jira.com/secure/attachment/10000/notes.txt"
+    );
+}
+
+#[test]
+fn download_attachment() {
+    let content = (0..=255).collect::<Vec<u8>>();
+    let server = MockServer::start(vec![Response::new(200)
+        .header("Content-Type", "application/octet-stream")
+        .body(content.clone())]);
+    let url = format!("{}/secure/attachment/10000/data.bin", server.url());
+
+    let bytes = jira(&server).issues().download_attachment(&url).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/secure/attachment/10000/data.bin");
+    assert!(request.header("Authorization").is_some());
+    assert_eq!(request.header("Accept"), Some("*/*"));
+    assert_eq!(bytes, content);
+}
+
+#[test]
+fn download_missing_attachment() {
+    let server = MockServer::start(vec![Response::new(404)]);
+    let url = format!("{}/secure/attachment/10000/data.bin", server.url());
+
+    assert!(jira(&server).issues().download_attachment(&url).is_err());
+}
+
+#[test]
+fn get_with_fields_and_expand() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {"summary": "an issue"}}"#,
+    )]);
+
+    let issue = jira(&server)
+        .issues()
+        .get_with_options(
+            "TEST-1",
+            &SearchOptions::builder()
+                .fields(vec!["summary", "status"])
+                .expand(vec!["changelog", "renderedFields"])
+                .build(),
+        )
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/api/latest/issue/TEST-1");
+    assert!(request.path.contains("fields=summary%2Cstatus"));
+    assert!(request.path.contains("expand=changelog%2CrenderedFields"));
+    assert_eq!(issue.summary(), Some("an issue".to_owned()));
+}
+
+#[test]
+fn get_with_selected_fields() {
+    let issue = r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {"summary": "an issue"}}"#;
+    let server = MockServer::start(vec![
+        Response::json(200, issue),
+        Response::json(200, issue),
+        Response::json(200, issue),
+    ]);
+    let issues = jira(&server).issues();
+
+    issues
+        .get_with("TEST-1", &["summary", "status"], &["changelog"], &[])
+        .unwrap();
+    issues
+        .get_with("TEST-1", &["*all", "-comment"], &[], &[])
+        .unwrap();
+    issues.get("TEST-1").unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path_only(), "/rest/api/latest/issue/TEST-1");
+    assert_eq!(requests[0].query("fields"), Some("summary,status".to_owned()));
+    assert_eq!(requests[0].query("expand"), Some("changelog".to_owned()));
+    assert_eq!(requests[1].query("fields"), Some("*all,-comment".to_owned()));
+    assert_eq!(requests[1].query("expand"), None);
+    assert_eq!(requests[1].query("properties"), None);
+    assert_eq!(requests[2].path, "/rest/api/latest/issue/TEST-1");
+}
+
+#[test]
+fn get_and_search_with_properties() {
+    let issue = r#"{
+        "self": "http://jira.com/rest/api/2/issue/1",
+        "id": "1",
+        "key": "TEST-1",
+        "fields": {},
+        "properties": {"sync.state": {"revision": 4, "source": "github"}}
+    }"#;
+    let server = MockServer::start(vec![
+        Response::json(200, issue),
+        Response::json(
+            200,
+            &format!(
+                r#"{{"startAt": 0, "maxResults": 50, "total": 1, "issues": [{}]}}"#,
+                issue
+            ),
+        ),
+    ]);
+    let issues = jira(&server).issues();
+
+    let fetched = issues
+        .get_with("TEST-1", &[], &[], &["sync.state", "triage"])
+        .unwrap();
+    let found = issues
+        .search(
+            "project = TEST",
+            &SearchOptions::builder()
+                .properties(vec!["sync.state"])
+                .build(),
+        )
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].query("properties"),
+        Some("sync.state,triage".to_owned())
+    );
+    assert_eq!(
+        requests[1].query("properties"),
+        Some("sync.state".to_owned())
+    );
+    assert_eq!(
+        fetched.properties["sync.state"],
+        serde_json::json!({ "revision": 4, "source": "github" })
+    );
+    let revision = found.values[0]
+        .property::<serde_json::Value>("sync.state")
+        .unwrap()
+        .unwrap()["revision"]
+        .clone();
+    assert_eq!(revision, 4);
+    assert!(found.values[0].property::<String>("triage").is_none());
+}
+
+#[test]
+fn get_with_rendered_fields() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/1",
+            "id": "1",
+            "key": "TEST-1",
+            "fields": {"description": "it is *really* broken"},
+            "renderedFields": {
+                "description": "<p>it is <b>really</b> broken</p>",
+                "environment": null,
+                "comment": {
+                    "comments": [{"id": "10000", "body": "<p>confirmed</p>"}],
+                    "maxResults": 1,
+                    "total": 1,
+                    "startAt": 0
+                },
+                "customfield_10010": "<p>notes</p>"
+            }
+        }"#,
+    )]);
+
+    let issue = jira(&server)
+        .issues()
+        .get_with("TEST-1", &["description", "comment"], &["renderedFields"], &[])
+        .unwrap();
+
+    let rendered = issue.rendered_fields.unwrap();
+    assert_eq!(
+        rendered.description,
+        Some("<p>it is <b>really</b> broken</p>".to_owned())
+    );
+    assert_eq!(rendered.environment, None);
+    assert_eq!(rendered.comment.unwrap().comments[0].body, "<p>confirmed</p>");
+    assert_eq!(rendered.other["customfield_10010"], "<p>notes</p>");
+    assert_eq!(
+        server.requests()[0].query("expand"),
+        Some("renderedFields".to_owned())
+    );
+}
+
+#[test]
+fn get_without_rendered_fields() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#,
+    )]);
+
+    let issue = jira(&server).issues().get("TEST-1").unwrap();
+
+    assert!(issue.rendered_fields.is_none());
+}
+
+const HISTORY: &str = r#"{
+    "id": "10000",
+    "author": {
+        "self": "http://jira.com/rest/api/2/user?username=fred",
+        "name": "fred",
+        "displayName": "Fred F. User",
+        "active": true
+    },
+    "created": "2019-01-01T10:00:00.000+0000",
+    "items": [{
+        "field": "status",
+        "fieldtype": "jira",
+        "from": "1",
+        "fromString": "Open",
+        "to": "3",
+        "toString": "In Progress"
+    }]
+}"#;
+
+#[test]
+fn changelog() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        &format!(
+            r#"{{"maxResults": 100, "startAt": 0, "total": 1, "isLast": true, "values": [{}]}}"#,
+            HISTORY
+        ),
+    )]);
+
+    let changelog = jira(&server)
+        .issues()
+        .changelog("TEST-1", &SearchOptions::default())
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].path_only(),
+        "/rest/api/latest/issue/TEST-1/changelog"
+    );
+    let history = &changelog.values[0];
+    assert_eq!(history.id, Some("10000".to_owned()));
+    assert_eq!(history.author.display_name, "Fred F. User");
+    assert_eq!(history.items[0].field, "status");
+    assert_eq!(history.items[0].from_string, Some("Open".to_owned()));
+    assert_eq!(history.items[0].to_string, Some("In Progress".to_owned()));
+}
+
+#[test]
+fn changelog_from_expanded_issue() {
+    let issue: Issue = serde_json::from_str(&format!(
+        r#"{{
+            "self": "http://jira.com/rest/api/2/issue/1",
+            "id": "1",
+            "key": "TEST-1",
+            "fields": {{}},
+            "changelog": {{"startAt": 0, "maxResults": 1, "total": 1, "histories": [{}]}}
+        }}"#,
+        HISTORY
+    ))
+    .unwrap();
+
+    let histories = issue.changelog.unwrap().histories;
+    assert_eq!(histories[0].items[0].to_string, Some("In Progress".to_owned()));
+}
+
+fn fields_with_status(status: &str) -> Fields {
+    serde_json::from_str(&format!(
+        r#"{{
+            "issuetype": {{"id": "10004"}},
+            "project": {{"key": "TEST"}},
+            "summary": "Something is broken",
+            "status": {}
+        }}"#,
+        status
+    ))
+    .unwrap()
+}
+
+#[test]
+fn deserialise_done_status() {
+    let fields = fields_with_status(
+        r#"{
+            "self": "https://jira.com/rest/api/2/status/10001",
+            "description": "",
+            "iconUrl": "https://jira.com/",
+            "name": "Done",
+            "id": "10001",
+            "statusCategory": {
+                "self": "https://jira.com/rest/api/2/statuscategory/3",
+                "id": 3,
+                "key": "done",
+                "colorName": "green",
+                "name": "Done"
+            }
+        }"#,
+    );
+
+    let status = fields.status.unwrap();
+    assert_eq!(status.name, "Done");
+    let category = status.status_category.unwrap();
+    assert_eq!(category.key, "done");
+    assert_eq!(category.color_name, "green");
+}
+
+#[test]
+fn deserialise_custom_in_progress_status() {
+    let fields = fields_with_status(
+        r#"{
+            "self": "https://jira.com/rest/api/2/status/10100",
+            "description": "Waiting on code review",
+            "iconUrl": "https://jira.com/",
+            "name": "In Review",
+            "id": "10100",
+            "statusCategory": {
+                "self": "https://jira.com/rest/api/2/statuscategory/4",
+                "id": 4,
+                "key": "indeterminate",
+                "colorName": "yellow",
+                "name": "In Progress"
+            }
+        }"#,
+    );
+
+    let status = fields.status.unwrap();
+    assert_eq!(status.name, "In Review");
+    assert_eq!(status.status_category.unwrap().key, "indeterminate");
+}
+
+#[test]
+fn status_is_not_sent_on_create() {
+    let fields = fields_with_status(
+        r#"{
+            "self": "https://jira.com/rest/api/2/status/10001",
+            "description": "",
+            "iconUrl": "https://jira.com/",
+            "name": "Done",
+            "id": "10001"
+        }"#,
+    );
+
+    let body = serde_json::to_value(&CreateIssue { fields }).unwrap();
+
+    assert!(body["fields"].get("status").is_none());
+}
+
+#[test]
+fn deserialise_resolution() {
+    let fields: Fields = serde_json::from_str(
+        r#"{
+            "issuetype": {"id": "10004"},
+            "project": {"key": "TEST"},
+            "summary": "Something was broken",
+            "resolution": {
+                "self": "https://jira.com/rest/api/2/resolution/1",
+                "id": "1",
+                "description": "A fix for this issue is checked into the tree and tested.",
+                "name": "Fixed"
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let resolution = fields.resolution.unwrap();
+    assert_eq!(resolution.id, Some("1".to_owned()));
+    assert_eq!(resolution.name, "Fixed");
+}
+
+fn create_fields() -> Fields {
+    Fields {
+        issuetype: IssueType {
+            id: "10003".to_owned(),
+            ..Default::default()
+        },
+        project: Project {
+            key: "TEST".to_owned(),
+        },
+        summary: "a subtask".to_owned(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn create_omits_unset_description_and_environment() {
+    let body = serde_json::to_value(&CreateIssue {
+        fields: create_fields(),
+    })
+    .unwrap();
+
+    let fields = body["fields"].as_object().unwrap();
+    assert!(!fields.contains_key("description"));
+    assert!(!fields.contains_key("environment"));
+}
+
+#[test]
+fn create_sends_large_descriptions_whole() {
+    let description = "a line of a long description\n".repeat(500);
+    let mut fields = create_fields();
+    fields.description = Some(description.clone());
+    fields.environment = Some("production".to_owned());
+
+    let body = serde_json::to_value(&CreateIssue { fields }).unwrap();
+
+    assert_eq!(body["fields"]["description"], description);
+    assert_eq!(body["fields"]["environment"], "production");
+}
+
+#[test]
+fn create_omits_missing_reporter() {
+    let body = serde_json::to_value(&CreateIssue {
+        fields: create_fields(),
+    })
+    .unwrap();
+
+    assert!(body["fields"].get("reporter").is_none());
+}
+
+#[test]
+fn create_omits_missing_assignee() {
+    let body = serde_json::to_value(&CreateIssue {
+        fields: create_fields(),
+    })
+    .unwrap();
+
+    assert!(body["fields"].get("assignee").is_none());
+}
+
+#[test]
+fn create_with_reporter_reports_field_errors() {
+    let server = MockServer::start(vec![Response::json(
+        400,
+        r#"{"errorMessages": [], "errors": {"reporter": "Field 'reporter' cannot be set. It is not on the appropriate screen, or unknown."}}"#,
+    )]);
+
+    let issue = CreateIssue::builder()
+        .project("TEST")
+        .summary("a subtask")
+        .issue_type("10003")
+        .reporter(Assignee::by_name("fred"))
+        .build()
+        .unwrap();
+    let result = jira(&server).issues().create(issue);
+
+    assert_eq!(
+        server.requests()[0].json()["fields"]["reporter"],
+        serde_json::json!({ "name": "fred" })
+    );
+    match result {
+        Err(Error::Fault { code, errors }) => {
+            assert_eq!(code.as_u16(), 400);
+            assert!(errors.errors["reporter"].contains("cannot be set"));
+        }
+        other => panic!("expected a field error, got {:?}", other),
+    }
+}
+
+#[test]
+fn create_subtask_with_parent() {
+    let server = MockServer::start(vec![Response::json(
+        201,
+        r#"{"id": "10002", "key": "TEST-2", "self": "http://jira.com/rest/api/2/issue/10002"}"#,
+    )]);
+
+    let mut fields = create_fields();
+    fields.parent = Some(Parent {
+        key: "TEST-1".to_owned(),
+    });
+    jira(&server)
+        .issues()
+        .create(CreateIssue { fields })
+        .unwrap();
+
+    let body = server.requests()[0].json();
+    assert_eq!(body["fields"]["parent"], serde_json::json!({ "key": "TEST-1" }));
+}
+
+#[test]
+fn create_omits_missing_parent() {
+    let body = serde_json::to_value(&CreateIssue {
+        fields: create_fields(),
+    })
+    .unwrap();
+
+    assert!(body["fields"].get("parent").is_none());
+}
+
+#[test]
+fn get_issue_without_priority() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/10001",
+            "id": "10001",
+            "key": "TEST-1",
+            "fields": {
+                "issuetype": {"id": "10000", "name": "Epic"},
+                "project": {"key": "TEST"},
+                "summary": "an epic"
+            }
+        }"#,
+    )]);
+
+    let issue = jira(&server).issues().get("TEST-1").unwrap();
+    let fields = serde_json::from_value::<Fields>(serde_json::to_value(&issue.fields).unwrap())
+        .unwrap();
+
+    assert!(issue.priority().is_none());
+    assert!(fields.priority.is_none());
+}
+
+#[test]
+fn create_omits_missing_priority() {
+    let body = serde_json::to_value(&CreateIssue {
+        fields: create_fields(),
+    })
+    .unwrap();
+
+    assert!(body["fields"].get("priority").is_none());
+}
+
+#[test]
+fn deserialise_security_level() {
+    let fields: Fields = serde_json::from_str(
+        r#"{
+            "issuetype": {"id": "10004"},
+            "project": {"key": "TEST"},
+            "summary": "Something is broken",
+            "security": {
+                "self": "http://jira.com/rest/api/2/securitylevel/10000",
+                "id": "10000",
+                "description": "Only the security team may see this issue",
+                "name": "Security team"
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let security = fields.security.unwrap();
+    assert_eq!(security.id, "10000");
+    assert_eq!(security.name, "Security team");
+    assert!(!fields.custom.contains_key("security"));
+}
+
+#[test]
+fn create_and_update_security_level() {
+    let server = MockServer::start(vec![
+        Response::json(
+            201,
+            r#"{"id": "10002", "key": "TEST-2", "self": "http://jira.com/rest/api/2/issue/10002"}"#,
+        ),
+        Response::new(204),
+    ]);
+    let issues = jira(&server).issues();
+
+    let issue = CreateIssue::builder()
+        .project("TEST")
+        .summary("a vulnerability")
+        .issue_type("10004")
+        .security("10000")
+        .build()
+        .unwrap();
+    issues.create(issue).unwrap();
+    issues
+        .update(
+            "TEST-2",
+            UpdateIssue {
+                fields: UpdateFields {
+                    security: Some(SecurityLevel::with_id("10001")),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].json()["fields"]["security"],
+        serde_json::json!({ "id": "10000" })
+    );
+    assert_eq!(
+        requests[1].json(),
+        serde_json::json!({ "fields": { "security": { "id": "10001" } } })
+    );
+    let body = serde_json::to_value(&CreateIssue {
+        fields: create_fields(),
+    })
+    .unwrap();
+    assert!(body["fields"].get("security").is_none());
+}
+
+#[test]
+fn create_and_replace_labels() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    let mut fields = create_fields();
+    fields.labels = vec!["backend".to_owned(), "urgent".to_owned()];
+    let body = serde_json::to_value(&CreateIssue { fields }).unwrap();
+    assert_eq!(body["fields"]["labels"], serde_json::json!(["backend", "urgent"]));
+    let body = serde_json::to_value(&CreateIssue {
+        fields: create_fields(),
+    })
+    .unwrap();
+    assert!(body["fields"].get("labels").is_none());
+
+    jira(&server)
+        .issues()
+        .update(
+            "TEST-1",
+            UpdateIssue {
+                fields: UpdateFields {
+                    labels: Some(vec!["frontend".to_owned()]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].json(),
+        serde_json::json!({ "fields": { "labels": ["frontend"] } })
+    );
+}
+
+#[test]
+fn add_and_remove_single_labels() {
+    let server = MockServer::start(vec![Response::new(204), Response::new(204)]);
+    let issues = jira(&server).issues();
+
+    issues.add_label("TEST-1", "backend").unwrap();
+    issues.remove_label("TEST-1", "urgent").unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "PUT");
+    assert_eq!(requests[0].path, "/rest/api/latest/issue/TEST-1");
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({ "fields": {}, "update": { "labels": [{ "add": "backend" }] } })
+    );
+    assert_eq!(
+        requests[1].json(),
+        serde_json::json!({ "fields": {}, "update": { "labels": [{ "remove": "urgent" }] } })
+    );
+}
+
+#[test]
+fn set_remaining_estimate() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .set_remaining_estimate("TEST-1", "2h")
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "PUT");
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "fields": {},
+            "update": { "timetracking": [{ "edit": { "remainingEstimate": "2h" } }] }
+        })
+    );
+}
+
+#[test]
+fn deserialise_labels() {
+    let fields: Fields = serde_json::from_str(
+        r#"{
+            "issuetype": {"id": "10004"},
+            "project": {"key": "TEST"},
+            "summary": "Something is broken",
+            "labels": ["backend"]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(fields.labels, vec!["backend".to_owned()]);
+    assert!(!fields.custom.contains_key("labels"));
+}
+
+#[test]
+fn deserialise_subtasks() {
+    let subtasks = r#"[{
+        "id": "10002",
+        "key": "TEST-2",
+        "self": "http://jira.com/rest/api/2/issue/10002",
+        "fields": {
+            "summary": "Write the migration",
+            "status": {
+                "self": "http://jira.com/rest/api/2/status/10000",
+                "description": "",
+                "iconUrl": "http://jira.com/images/icons/statuses/open.png",
+                "name": "To Do",
+                "id": "10000"
+            },
+            "issuetype": {"id": "10003", "name": "Sub-task", "subtask": true}
+        }
+    }, {
+        "id": "10003",
+        "key": "TEST-3",
+        "self": "http://jira.com/rest/api/2/issue/10003",
+        "fields": {"summary": "Backfill old rows"}
+    }]"#;
+    let fields: Fields = serde_json::from_str(&format!(
+        r#"{{
+            "issuetype": {{"id": "10004"}},
+            "project": {{"key": "TEST"}},
+            "summary": "Something is broken",
+            "subtasks": {}
+        }}"#,
+        subtasks
+    ))
+    .unwrap();
+    let issue: Issue = serde_json::from_str(&format!(
+        r#"{{"self": "http://jira.com/rest/api/2/issue/10001", "id": "10001", "key": "TEST-1", "fields": {{"subtasks": {}}}}}"#,
+        subtasks
+    ))
+    .unwrap();
+
+    assert_eq!(fields.subtasks.len(), 2);
+    assert_eq!(fields.subtasks[0].key, "TEST-2");
+    assert_eq!(fields.subtasks[0].fields.summary, "Write the migration");
+    assert_eq!(
+        fields.subtasks[0].fields.status.as_ref().unwrap().name,
+        "To Do"
+    );
+    assert!(fields.subtasks[0].fields.issuetype.as_ref().unwrap().subtask);
+    assert_eq!(fields.subtasks[1].id, "10003");
+    assert!(fields.subtasks[1].fields.status.is_none());
+    assert!(!fields.custom.contains_key("subtasks"));
+    assert!(serde_json::to_value(&fields).unwrap().get("subtasks").is_none());
+    let keys = issue
+        .subtasks()
+        .into_iter()
+        .map(|subtask| subtask.key)
+        .collect::<Vec<String>>();
+    assert_eq!(keys, vec!["TEST-2", "TEST-3"]);
+}
+
+#[test]
+fn deserialise_versions() {
+    let fields: Fields = serde_json::from_str(
+        r#"{
+            "issuetype": {"id": "10004"},
+            "project": {"key": "TEST"},
+            "summary": "Something is broken",
+            "fixVersions": [{
+                "self": "http://jira.com/rest/api/2/version/10001",
+                "id": "10001",
+                "name": "1.1",
+                "archived": false,
+                "released": false
+            }],
+            "versions": [{
+                "self": "http://jira.com/rest/api/2/version/10000",
+                "id": "10000",
+                "name": "1.0",
+                "archived": false,
+                "released": true
+            }]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(fields.fix_versions[0].name, "1.1");
+    assert!(!fields.fix_versions[0].released);
+    assert_eq!(fields.versions[0].id, "10000");
+    assert!(fields.versions[0].released);
+
+    let fields = fields_with_status("null");
+    assert!(fields.fix_versions.is_empty());
+    assert!(fields.versions.is_empty());
+}
+
+#[test]
+fn create_with_fix_version() {
+    let mut fields = create_fields();
+    fields.fix_versions = vec![Version::new("10001")];
+
+    let body = serde_json::to_value(&CreateIssue { fields }).unwrap();
+
+    assert_eq!(body["fields"]["fixVersions"], serde_json::json!([{ "id": "10001" }]));
+    assert!(body["fields"].get("versions").is_none());
+}
+
+#[test]
+fn bulk_create_reports_partial_failures() {
+    let server = MockServer::start(vec![Response::json(
+        201,
+        r#"{
+            "issues": [
+                {"id": "10000", "key": "TEST-24", "self": "http://jira.com/rest/api/2/issue/10000"}
+            ],
+            "errors": [{
+                "status": 400,
+                "elementErrors": {
+                    "errorMessages": [],
+                    "errors": {"issuetype": "The issue type selected is invalid."}
+                },
+                "failedElementNumber": 1
+            }]
+        }"#,
+    )]);
+
+    let response = jira(&server)
+        .issues()
+        .bulk_create(vec![
+            CreateIssue {
+                fields: create_fields(),
+            },
+            CreateIssue {
+                fields: create_fields(),
+            },
+        ])
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/issue/bulk");
+    let updates = request.json()["issueUpdates"].clone();
+    assert_eq!(updates.as_array().unwrap().len(), 2);
+    assert_eq!(updates[0]["fields"]["summary"], "a subtask");
+    assert_eq!(response.issues[0].key, "TEST-24");
+    assert_eq!(response.errors[0].status, Some(400));
+    assert_eq!(response.errors[0].failed_element_number, 1);
+    assert_eq!(
+        response.errors[0].element_errors.errors["issuetype"],
+        "The issue type selected is invalid."
+    );
+}
+
+#[test]
+fn bulk_create_sends_batches_of_fifty() {
+    let server = MockServer::start(vec![
+        Response::json(201, r#"{"issues": [], "errors": []}"#),
+        Response::json(
+            201,
+            r#"{"issues": [], "errors": [{
+                "status": 400,
+                "elementErrors": {"errorMessages": ["invalid"], "errors": {}},
+                "failedElementNumber": 2
+            }]}"#,
+        ),
+    ]);
+    let issues = (0..60)
+        .map(|_| CreateIssue {
+            fields: create_fields(),
+        })
+        .collect();
+
+    let response = jira(&server).issues().bulk_create(issues).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].json()["issueUpdates"].as_array().unwrap().len(), 50);
+    assert_eq!(requests[1].json()["issueUpdates"].as_array().unwrap().len(), 10);
+    // failures are numbered against the full list of issues
+    assert_eq!(response.errors[0].failed_element_number, 52);
+}
+
+#[test]
+fn rank_before_issue() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .rank(RankIssues::before(
+            vec!["TEST-2".to_owned(), "TEST-3".to_owned()],
+            "TEST-1",
+        ))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "PUT");
+    assert_eq!(request.path, "/rest/agile/latest/issue/rank");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({ "issues": ["TEST-2", "TEST-3"], "rankBeforeIssue": "TEST-1" })
+    );
+}
+
+#[test]
+fn rank_after_issue() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .rank(RankIssues::after(vec!["TEST-2".to_owned()], "TEST-1"))
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].json(),
+        serde_json::json!({ "issues": ["TEST-2"], "rankAfterIssue": "TEST-1" })
+    );
+}
+
+#[test]
+fn rank_after_keeps_order_across_batches() {
+    let server = MockServer::start(vec![Response::new(204), Response::new(204)]);
+    let issues = (2..62).map(|n| format!("TEST-{}", n)).collect::<Vec<_>>();
+
+    jira(&server)
+        .issues()
+        .rank(RankIssues::after(issues, "TEST-1"))
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].json()["issues"].as_array().unwrap().len(), 50);
+    assert_eq!(requests[0].json()["rankAfterIssue"], "TEST-1");
+    assert_eq!(requests[1].json()["issues"][0], "TEST-52");
+    assert_eq!(requests[1].json()["rankAfterIssue"], "TEST-51");
+}
+
+#[test]
+fn rank_reports_issues_which_could_not_be_ranked() {
+    let server = MockServer::start(vec![Response::json(
+        207,
+        r#"{"entries": [
+            {"issueId": 10001, "issueKey": "TEST-2", "status": 200},
+            {"issueId": 10002, "issueKey": "TEST-3", "status": 400, "errors": ["Issue is not on a board"]}
+        ]}"#,
+    )]);
+
+    let result = jira(&server).issues().rank(RankIssues::before(
+        vec!["TEST-2".to_owned(), "TEST-3".to_owned()],
+        "TEST-1",
+    ));
+
+    match result {
+        Err(Error::Fault { code, errors }) => {
+            assert_eq!(code.as_u16(), 207);
+            assert_eq!(
+                errors.error_messages,
+                vec!["TEST-3: Issue is not on a board".to_owned()]
+            );
+        }
+        other => panic!("expected a fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn deserialise_partial_time_tracking() {
+    let mut fields = create_fields();
+    fields.timetracking = serde_json::from_str(
+        r#"{
+            "remainingEstimate": "1d 2h",
+            "timeSpent": "3h",
+            "remainingEstimateSeconds": 36000,
+            "timeSpentSeconds": 10800
+        }"#,
+    )
+    .unwrap();
+
+    let tracking = fields.timetracking.unwrap();
+    assert_eq!(tracking.original_estimate, None);
+    assert_eq!(tracking.original_estimate_seconds, None);
+    assert_eq!(tracking.remaining_estimate, Some("1d 2h".to_owned()));
+    assert_eq!(tracking.remaining_estimate_seconds, Some(36000));
+    assert_eq!(tracking.time_spent, Some("3h".to_owned()));
+    assert_eq!(tracking.time_spent_seconds, Some(10800));
+}
+
+#[test]
+fn create_with_original_estimate() {
+    let server = MockServer::start(vec![Response::json(
+        201,
+        r#"{"id": "10002", "key": "TEST-2", "self": "http://jira.com/rest/api/2/issue/10002"}"#,
+    )]);
+
+    let mut fields = create_fields();
+    fields.timetracking = Some(TimeTracking {
+        original_estimate: Some("2d".to_owned()),
+        ..Default::default()
+    });
+    jira(&server)
+        .issues()
+        .create(CreateIssue { fields })
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].json()["fields"]["timetracking"],
+        serde_json::json!({ "originalEstimate": "2d" })
+    );
+}
+
+#[test]
+fn iter_is_lazy() {
+    let server = MockServer::start(vec![issues_page(0, 2, 1, &[1])]);
+    let board = board();
+    let options = Default::default();
+    let jira = jira(&server);
+
+    let mut issues = jira.issues().iter(&board, &options);
+
+    assert!(server.requests().is_empty());
+    assert_eq!(issues.next().unwrap().unwrap().id, "1");
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn iter_yields_failed_pages_as_errors() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 4, &[1, 2]),
+        Response::json(500, r#"{"errorMessages": ["Internal server error"], "errors": {}}"#),
+    ]);
+    let board = board();
+    let options = Default::default();
+
+    let results = jira(&server)
+        .issues()
+        .iter(&board, &options)
+        .collect::<Vec<_>>();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().id, "1");
+    assert_eq!(results[1].as_ref().unwrap().id, "2");
+    match results[2] {
+        Err(Error::Fault { ref code, .. }) => assert_eq!(code.as_u16(), 500),
+        ref other => panic!("expected a fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn iter_requests_the_configured_page_size() {
+    let ids = (1..=250).collect::<Vec<u64>>();
+    let server = MockServer::start(vec![
+        issues_page(0, 100, 250, &ids[0..100]),
+        issues_page(100, 100, 250, &ids[100..200]),
+        issues_page(200, 100, 250, &ids[200..250]),
+    ]);
+    let board = board();
+    let options = SearchOptions::builder().max_results(100).build();
+
+    let count = jira(&server).issues().iter(&board, &options).count();
+
+    assert_eq!(count, 250);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    for request in requests.iter() {
+        assert_eq!(request.query("maxResults"), Some("100".to_owned()));
+    }
+}
+
+#[test]
+fn iter_page_size_is_capped_by_the_server() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 3, &[1, 2]),
+        issues_page(2, 2, 3, &[3]),
+    ]);
+    let board = board();
+    let options = SearchOptions::builder().max_results(100).build();
+
+    let count = jira(&server).issues().iter(&board, &options).count();
+
+    assert_eq!(count, 3);
+    let requests = server.requests();
+    assert_eq!(requests[0].query("maxResults"), Some("100".to_owned()));
+    assert_eq!(requests[1].query("maxResults"), Some("2".to_owned()));
+}
+
+const SOURCE_ISSUE: &str = r#"{
+    "self": "http://jira.com/rest/api/2/issue/10001",
+    "id": "10001",
+    "key": "TEST-1",
+    "fields": {
+        "summary": "Something is broken",
+        "description": "It broke",
+        "issuetype": {"self": "http://jira.com/rest/api/2/issuetype/10004", "id": "10004", "name": "Bug", "subtask": false},
+        "project": {"self": "http://jira.com/rest/api/2/project/10000", "id": "10000", "key": "TEST", "name": "Test"},
+        "components": [{"self": "http://jira.com/rest/api/2/component/10000", "id": "10000", "name": "backend"}],
+        "priority": {"self": "http://jira.com/rest/api/2/priority/3", "iconUrl": "http://jira.com/images/major.svg", "name": "Major", "id": "3"},
+        "status": {"self": "http://jira.com/rest/api/2/status/6", "description": "", "iconUrl": "", "name": "Closed", "id": "6"},
+        "resolution": {"id": "1", "name": "Fixed"},
+        "created": "2018-07-11T16:56:12.000+0000"
+    }
+}"#;
+
+#[test]
+fn clone_issue() {
+    let server = MockServer::start(vec![
+        Response::json(200, SOURCE_ISSUE),
+        Response::json(
+            201,
+            r#"{"id": "10002", "key": "TEST-2", "self": "http://jira.com/rest/api/2/issue/10002"}"#,
+        ),
+    ]);
+
+    let created = jira(&server).issues().clone("TEST-1", None).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/rest/api/latest/issue/TEST-1");
+    assert_eq!(requests[1].method, "POST");
+    assert_eq!(requests[1].path, "/rest/api/latest/issue");
+    let fields = requests[1].json()["fields"].clone();
+    assert_eq!(fields["summary"], "CLONE - Something is broken");
+    assert_eq!(fields["description"], "It broke");
+    assert_eq!(fields["issuetype"], serde_json::json!({ "id": "10004" }));
+    assert_eq!(fields["project"], serde_json::json!({ "key": "TEST" }));
+    assert_eq!(fields["components"][0]["id"], "10000");
+    assert_eq!(fields["priority"]["id"], "3");
+    assert!(fields.get("status").is_none());
+    assert!(fields.get("resolution").is_none());
+    assert!(fields.get("created").is_none());
+    assert_eq!(created.key, "TEST-2");
+}
+
+#[test]
+fn clone_issue_with_overrides() {
+    let server = MockServer::start(vec![
+        Response::json(200, SOURCE_ISSUE),
+        Response::json(
+            201,
+            r#"{"id": "10002", "key": "TEST-2", "self": "http://jira.com/rest/api/2/issue/10002"}"#,
+        ),
+    ]);
+
+    jira(&server)
+        .issues()
+        .clone(
+            "TEST-1",
+            Some(Fields {
+                summary: "Something else is broken".to_owned(),
+                labels: vec!["cloned".to_owned()],
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let fields = server.requests()[1].json()["fields"].clone();
+    assert_eq!(fields["summary"], "Something else is broken");
+    assert_eq!(fields["labels"], serde_json::json!(["cloned"]));
+    assert_eq!(fields["description"], "It broke");
+}
+
+#[test]
+fn clone_issue_with_summary_override_skips_prefix() {
+    let server = MockServer::start(vec![
+        Response::json(200, SOURCE_ISSUE),
+        Response::json(
+            201,
+            r#"{"id": "10002", "key": "TEST-2", "self": "http://jira.com/rest/api/2/issue/10002"}"#,
+        ),
+    ]);
+
+    jira(&server)
+        .issues()
+        .clone(
+            "TEST-1",
+            Some(Fields {
+                summary: "CLONE - Something is broken again".to_owned(),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let fields = server.requests()[1].json()["fields"].clone();
+    assert_eq!(fields["summary"], "CLONE - Something is broken again");
+}
+
+#[test]
+fn clone_issue_with_adf_description() {
+    let document = serde_json::json!({
+        "version": 1,
+        "type": "doc",
+        "content": [{"type": "paragraph", "content": [{"type": "text", "text": "It broke"}]}]
+    });
+    let mut source: serde_json::Value = serde_json::from_str(SOURCE_ISSUE).unwrap();
+    source["fields"]["description"] = document.clone();
+    let server = MockServer::start(vec![
+        Response::json(200, &source.to_string()),
+        Response::json(
+            201,
+            r#"{"id": "10002", "key": "TEST-2", "self": "http://jira.com/rest/api/2/issue/10002"}"#,
+        ),
+    ]);
+
+    jira(&server).issues().clone("TEST-1", None).unwrap();
+
+    let fields = server.requests()[1].json()["fields"].clone();
+    assert_eq!(fields["description"], document);
+    assert_eq!(fields["summary"], "CLONE - Something is broken");
+}
+
+#[test]
+fn assign_issue_by_account_id() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .assign(
+            "TEST-1",
+            Some(Assignee::by_account_id("5b10a2844c20165700ede21g")),
+        )
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].json(),
+        serde_json::json!({ "accountId": "5b10a2844c20165700ede21g" })
+    );
+}
+
+#[test]
+fn deserialise_cloud_assignee() {
+    let fields: Fields = serde_json::from_str(
+        r#"{
+            "issuetype": {"id": "10004"},
+            "project": {"key": "TEST"},
+            "summary": "Something is broken",
+            "assignee": {
+                "self": "https://example.atlassian.net/rest/api/2/user?accountId=5b10a2844c20165700ede21g",
+                "accountId": "5b10a2844c20165700ede21g",
+                "displayName": "Mia Krystof",
+                "active": true
+            },
+            "reporter": {"name": "fred", "displayName": "Fred F. User"}
+        }"#,
+    )
+    .unwrap();
+
+    let assignee = fields.assignee.unwrap();
+    assert_eq!(assignee.name, None);
+    assert_eq!(
+        assignee.account_id,
+        Some("5b10a2844c20165700ede21g".to_owned())
+    );
+    assert_eq!(fields.reporter.unwrap().name, Some("fred".to_owned()));
+}
+
+#[test]
+fn archive_issues_partially() {
+    let server = MockServer::start(vec![
+        Response::json(
+            200,
+            r#"{
+                "errors": {
+                    "issuesInArchivedProjects": {
+                        "count": 1,
+                        "issueIdsOrKeys": ["TEST-2"],
+                        "message": "Issues are already archived."
+                    }
+                },
+                "numberOfIssuesUpdated": 1
+            }"#,
+        ),
+        Response::json(200, r#"{"errors": {}, "numberOfIssuesUpdated": 2}"#),
+    ]);
+    let keys = vec!["TEST-1".to_owned(), "TEST-2".to_owned()];
+    let issues = jira(&server).issues();
+
+    let archived = issues.archive(&keys).unwrap();
+    let restored = issues.restore(&keys).unwrap();
+
+    assert_eq!(archived.number_archived, 1);
+    let error = &archived.errors["issuesInArchivedProjects"];
+    assert_eq!(error.count, 1);
+    assert_eq!(error.issue_ids_or_keys, vec!["TEST-2"]);
+    assert_eq!(restored.number_archived, 2);
+    assert!(restored.errors.is_empty());
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "PUT");
+    assert_eq!(requests[0].path, "/rest/api/latest/issue/archive");
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({ "issueIdsOrKeys": ["TEST-1", "TEST-2"] })
+    );
+    assert_eq!(requests[1].path, "/rest/api/latest/issue/unarchive");
+}
+
+#[test]
+fn build_minimal_issue() {
+    let issue = CreateIssue::builder()
+        .project("TEST")
+        .summary("Something is broken")
+        .issue_type("10004")
+        .priority("3")
+        .labels(vec!["triage"])
+        .components(vec!["backend"])
+        .custom_field("customfield_10020", 5)
+        .build()
+        .unwrap();
+
+    let fields = serde_json::to_value(&issue).unwrap()["fields"].clone();
+    assert_eq!(fields["project"], serde_json::json!({ "key": "TEST" }));
+    assert_eq!(fields["summary"], "Something is broken");
+    assert_eq!(fields["issuetype"], serde_json::json!({ "id": "10004" }));
+    assert_eq!(fields["priority"], serde_json::json!({ "id": "3" }));
+    assert_eq!(fields["labels"], serde_json::json!(["triage"]));
+    assert_eq!(fields["components"], serde_json::json!([{ "name": "backend" }]));
+    assert_eq!(fields["customfield_10020"], 5);
+}
+
+#[test]
+fn build_issue_without_components() {
+    let issue = CreateIssue::builder()
+        .project("TEST")
+        .summary("Something is broken")
+        .issue_type("10004")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&issue).unwrap(),
+        serde_json::json!({
+            "fields": {
+                "issuetype": { "id": "10004" },
+                "project": { "key": "TEST" },
+                "summary": "Something is broken"
+            }
+        })
+    );
+}
+
+#[test]
+fn build_issue_without_summary() {
+    match CreateIssue::builder()
+        .project("TEST")
+        .issue_type("10004")
+        .build()
+    {
+        Err(Error::MissingRequiredFields(fields)) => assert_eq!(fields, vec!["summary"]),
+        other => panic!("expected missing fields, got {:?}", other),
+    }
+}
+
+#[test]
+fn build_issue_with_unserializable_custom_field() {
+    let mut value = std::collections::HashMap::new();
+    value.insert(vec![1], "not a valid key");
+
+    match CreateIssue::builder()
+        .project("TEST")
+        .summary("Something is broken")
+        .issue_type("10004")
+        .custom_field("customfield_10020", value)
+        .custom_field("customfield_10021", 5)
+        .build()
+    {
+        Err(Error::Serde(_)) => (),
+        other => panic!("expected a serialization error, got {:?}", other),
+    }
+}
+
+#[test]
+fn validate_invalid_jql() {
+    let server = MockServer::start(vec![
+        Response::json(
+            400,
+            r#"{"errorMessages": ["Field 'priorty' does not exist or you do not have permission to view it."], "errors": {}}"#,
+        ),
+        Response::json(
+            200,
+            r#"{"startAt": 0, "maxResults": 0, "total": 4, "issues": [], "warningMessages": ["The value 'Nope' does not exist for the field 'status'."]}"#,
+        ),
+    ]);
+    let issues = jira(&server).issues();
+
+    let errors = issues.validate_jql("priorty = High").unwrap();
+    let warnings = issues.validate_jql("status = Nope").unwrap();
+
+    assert_eq!(
+        errors,
+        vec!["Field 'priorty' does not exist or you do not have permission to view it."]
+    );
+    assert_eq!(
+        warnings,
+        vec!["The value 'Nope' does not exist for the field 'status'."]
+    );
+    let request = &server.requests()[0];
+    assert_eq!(request.query("validateQuery"), Some("strict".to_owned()));
+    assert_eq!(request.query("maxResults"), Some("0".to_owned()));
+    assert_eq!(request.query("jql"), Some("priorty = High".to_owned()));
+}
+
+#[test]
+fn search_with_query_validation() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"startAt": 0, "maxResults": 50, "total": 0, "issues": [], "warningMessages": ["The value 'Nope' does not exist for the field 'status'."]}"#,
+    )]);
+
+    let page = jira(&server)
+        .issues()
+        .search(
+            "status = Nope",
+            &SearchOptions::builder()
+                .query_validation(ValidateQuery::Warn)
+                .build(),
+        )
+        .unwrap();
+
+    assert_eq!(page.warning_messages.len(), 1);
+    assert_eq!(
+        server.requests()[0].query("validateQuery"),
+        Some("warn".to_owned())
+    );
+}
+
+#[test]
+fn find_assignable_users() {
+    let user = r#"[{
+        "self": "http://jira.com/rest/api/2/user?username=fred",
+        "name": "fred",
+        "displayName": "Fred F. User",
+        "active": true
+    }]"#;
+    let server = MockServer::start(vec![Response::json(200, user), Response::json(200, user)]);
+    let issues = jira(&server).issues();
+
+    let users = issues.assignable_users("TEST-1", "Fred F").unwrap();
+    issues.assignable_users_by_username("TEST-1", "fred").unwrap();
+
+    assert_eq!(users[0].display_name, "Fred F. User");
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path_only(),
+        "/rest/api/latest/user/assignable/search"
+    );
+    assert_eq!(requests[0].query("issueKey"), Some("TEST-1".to_owned()));
+    assert_eq!(requests[0].query("query"), Some("Fred F".to_owned()));
+    assert_eq!(requests[1].query("issueKey"), Some("TEST-1".to_owned()));
+    assert_eq!(requests[1].query("username"), Some("fred".to_owned()));
+}
+
+#[test]
+fn find_assignable_users_by_deployment() {
+    let user = r#"[{
+        "self": "http://jira.com/rest/api/2/user?username=fred",
+        "name": "fred",
+        "displayName": "Fred F. User",
+        "active": true
+    }]"#;
+    let server = MockServer::start(vec![Response::json(200, user), Response::json(200, user)]);
+    let cloud = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .deployment(Deployment::Cloud)
+    .build()
+    .unwrap();
+
+    jira(&server)
+        .issues()
+        .find_assignable("TEST-1", "fred")
+        .unwrap();
+    cloud.issues().find_assignable("TEST-1", "Fred F").unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].query("issueKey"), Some("TEST-1".to_owned()));
+    assert_eq!(requests[0].query("username"), Some("fred".to_owned()));
+    assert_eq!(requests[1].query("issueKey"), Some("TEST-1".to_owned()));
+    assert_eq!(requests[1].query("query"), Some("Fred F".to_owned()));
 }
@@ -0,0 +1,53 @@
+extern crate jira_rs;
+
+use jira_rs::{Direction, Jql};
+
+#[test]
+fn combines_clauses_with_and() {
+    let jql = Jql::new()
+        .project("ABC")
+        .status("In Progress")
+        .order_by("priority", Direction::Desc)
+        .order_by("created", Direction::Asc)
+        .build();
+
+    assert_eq!(
+        jql,
+        r#"project = "ABC" AND status = "In Progress" ORDER BY priority DESC, created ASC"#
+    );
+}
+
+#[test]
+fn escapes_embedded_quotes() {
+    let jql = Jql::new().eq("summary", r#"say "hi" \o/"#).build();
+
+    assert_eq!(jql, r#"summary = "say \"hi\" \\o/""#);
+}
+
+#[test]
+fn quotes_reserved_words() {
+    let jql = Jql::new().assignee("empty").project("AND").build();
+
+    assert_eq!(jql, r#"assignee = "empty" AND project = "AND""#);
+}
+
+#[test]
+fn combines_queries_with_or() {
+    let jql = Jql::new()
+        .status("Open")
+        .or(Jql::new().status("Reopened").project("ABC"))
+        .and(&Jql::raw("assignee = currentUser()"))
+        .build();
+
+    assert_eq!(
+        jql,
+        r#"(status = "Open" OR (status = "Reopened" AND project = "ABC")) AND (assignee = currentUser())"#
+    );
+}
+
+#[test]
+fn orders_without_clauses() {
+    let jql = Jql::new().order_by("rank", Direction::Asc).to_string();
+
+    assert_eq!(jql, "ORDER BY rank ASC");
+}
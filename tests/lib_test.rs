@@ -0,0 +1,642 @@
+extern crate flate2;
+extern crate jira_rs;
+extern crate reqwest;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use std::time::Duration;
+use support::{jira, MockServer, Response};
+
+const ISSUE: &str = r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#;
+
+#[test]
+fn basic_credentials() {
+    let server = MockServer::start(vec![Response::json(200, ISSUE)]);
+    let jira = Jira::new(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .unwrap();
+
+    jira.issues().get("TEST-1").unwrap();
+
+    // base64 of user:pwd
+    assert_eq!(
+        server.requests()[0].header("Authorization"),
+        Some("Basic dXNlcjpwd2Q=")
+    );
+}
+
+#[test]
+fn bearer_credentials() {
+    let server = MockServer::start(vec![Response::json(200, ISSUE)]);
+    let jira = Jira::new(server.url(), Credentials::Bearer("token".to_owned())).unwrap();
+
+    jira.issues().get("TEST-1").unwrap();
+
+    assert_eq!(
+        server.requests()[0].header("Authorization"),
+        Some("Bearer token")
+    );
+}
+
+#[test]
+fn personal_access_token_credentials() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"self": "http://jira.com/rest/api/2/user?username=fred", "name": "fred", "displayName": "Fred F. User", "active": true}"#,
+    )]);
+    let credentials = Credentials::PersonalAccessToken("NjM4OTY0Mzc5NjE3".to_owned());
+    let jira = Jira::new(server.url(), credentials.clone()).unwrap();
+
+    let user = jira.myself().unwrap();
+
+    assert_eq!(user.name, Some("fred".to_owned()));
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/rest/api/latest/myself");
+    assert_eq!(
+        request.header("Authorization"),
+        Some("Bearer NjM4OTY0Mzc5NjE3")
+    );
+    assert!(!format!("{:?}", credentials).contains("NjM4OTY0Mzc5NjE3"));
+    assert!(!format!("{:?}", jira).contains("NjM4OTY0Mzc5NjE3"));
+}
+
+#[test]
+fn deployment_is_detected_from_the_host() {
+    let credentials = Credentials::Basic("user".to_owned(), "pwd".to_owned());
+
+    let cloud = Jira::new("https://example.atlassian.net", credentials.clone()).unwrap();
+    let server = Jira::new("https://jira.example.com/jira", credentials.clone()).unwrap();
+    let configured = Jira::builder("https://jira.example.com", credentials)
+        .deployment(Deployment::Cloud)
+        .build()
+        .unwrap();
+
+    assert_eq!(cloud.deployment(), Deployment::Cloud);
+    assert_eq!(server.deployment(), Deployment::Server);
+    assert_eq!(configured.deployment(), Deployment::Cloud);
+    assert_eq!(
+        Deployment::detect(&Jira::cloud_gateway_host("1234")),
+        Deployment::Cloud
+    );
+}
+
+#[test]
+fn auto_credentials_are_basic_for_self_hosted_jira() {
+    let server = MockServer::start(vec![Response::json(200, ISSUE)]);
+    let jira = Jira::new(
+        server.url(),
+        Credentials::Auto("user".to_owned(), "pwd".to_owned()),
+    )
+    .unwrap();
+
+    jira.issues().get("TEST-1").unwrap();
+
+    assert_eq!(
+        server.requests()[0].header("Authorization"),
+        Some("Basic dXNlcjpwd2Q=")
+    );
+    match Credentials::Auto("user".to_owned(), "pwd".to_owned()).resolve("https://jira.example.com")
+    {
+        Credentials::Basic(user, pass) => assert_eq!((user, pass), ("user".into(), "pwd".into())),
+        other => panic!("expected basic credentials, got {:?}", other),
+    }
+}
+
+#[test]
+fn auto_credentials_are_bearer_for_jira_cloud() {
+    let auto = Credentials::Auto("user@example.com".to_owned(), "token".to_owned());
+
+    for host in &[
+        "https://example.atlassian.net",
+        "https://Example.Atlassian.NET/",
+        "https://api.atlassian.com/ex/jira/1324a887-45db-1bf4-1e99-ef0ff456d421",
+    ] {
+        match auto.resolve(host) {
+            Credentials::Bearer(token) => assert_eq!(token, "token"),
+            other => panic!("expected bearer credentials for {}, got {:?}", host, other),
+        }
+    }
+}
+
+#[test]
+fn explicit_credentials_are_never_resolved() {
+    match Credentials::Bearer("token".to_owned()).resolve("https://jira.example.com") {
+        Credentials::Bearer(token) => assert_eq!(token, "token"),
+        other => panic!("expected bearer credentials, got {:?}", other),
+    }
+    match Credentials::Basic("user".to_owned(), "pwd".to_owned())
+        .resolve("https://example.atlassian.net")
+    {
+        Credentials::Basic(user, _) => assert_eq!(user, "user"),
+        other => panic!("expected basic credentials, got {:?}", other),
+    }
+}
+
+#[test]
+fn cloud_gateway_host() {
+    assert_eq!(
+        Jira::cloud_gateway_host("1324a887-45db-1bf4-1e99-ef0ff456d421"),
+        "https://api.atlassian.com/ex/jira/1324a887-45db-1bf4-1e99-ef0ff456d421"
+    );
+}
+
+#[test]
+fn slow_responses_time_out() {
+    let server = MockServer::start(vec![
+        Response::json(200, ISSUE).delay(Duration::from_secs(2))
+    ]);
+    let jira = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .timeout(Duration::from_millis(200))
+    .connect_timeout(Duration::from_millis(200))
+    .build()
+    .unwrap();
+
+    match jira.issues().get("TEST-1") {
+        Err(Error::Timeout) => (),
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+}
+
+fn client_with_header() -> reqwest::Client {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("X-Custom", "custom".parse().unwrap());
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn requests_use_supplied_client() {
+    let server = MockServer::start(vec![
+        Response::json(200, ISSUE),
+        Response::json(200, ISSUE),
+    ]);
+    let credentials = Credentials::Basic("user".to_owned(), "pwd".to_owned());
+
+    Jira::from_client(server.url(), credentials.clone(), client_with_header())
+        .unwrap()
+        .issues()
+        .get("TEST-1")
+        .unwrap();
+    Jira::builder(server.url(), credentials)
+        .client(client_with_header())
+        .build()
+        .unwrap()
+        .issues()
+        .get("TEST-1")
+        .unwrap();
+
+    for request in server.requests() {
+        assert_eq!(request.header("X-Custom"), Some("custom"));
+    }
+}
+
+#[test]
+fn each_verb_sends_its_method_and_path() {
+    let server = MockServer::start(vec![
+        Response::json(200, r#"{"id": "1"}"#),
+        Response::json(201, r#"{"id": "2"}"#),
+        Response::new(204),
+        Response::json(200, r#"{"id": "4"}"#),
+        Response::new(204),
+    ]);
+    let jira = jira(&server);
+    let body = serde_json::json!({ "name": "x" });
+
+    let got: serde_json::Value = jira.get("api", "/thing/1").unwrap();
+    let posted: serde_json::Value = jira.post("api", "/thing", &body).unwrap();
+    let put: () = jira.put("api", "/thing/3", &body).unwrap();
+    let patched: serde_json::Value = jira.patch("api", "/thing/4", &body).unwrap();
+    let deleted: Option<serde_json::Value> = jira.delete("api", "/thing/5").unwrap();
+
+    assert_eq!(got["id"], "1");
+    assert_eq!(posted["id"], "2");
+    assert_eq!(put, ());
+    assert_eq!(patched["id"], "4");
+    assert_eq!(deleted, None);
+    let requests = server
+        .requests()
+        .iter()
+        .map(|request| (request.method.clone(), request.path.clone()))
+        .collect::<Vec<(String, String)>>();
+    assert_eq!(
+        requests,
+        vec![
+            ("GET".to_owned(), "/rest/api/latest/thing/1".to_owned()),
+            ("POST".to_owned(), "/rest/api/latest/thing".to_owned()),
+            ("PUT".to_owned(), "/rest/api/latest/thing/3".to_owned()),
+            ("PATCH".to_owned(), "/rest/api/latest/thing/4".to_owned()),
+            ("DELETE".to_owned(), "/rest/api/latest/thing/5".to_owned()),
+        ]
+    );
+    assert_eq!(server.requests()[3].json(), body);
+}
+
+#[test]
+fn empty_responses_succeed() {
+    let server = MockServer::start(vec![
+        Response::new(204),
+        Response::new(204),
+        Response::new(200).body(b"\r\n".to_vec()),
+        Response::new(204),
+    ]);
+    let jira = jira(&server);
+
+    let unit: () = jira.post("api", "/thing", "x").unwrap();
+    let none: Option<Issue> = jira.post("api", "/thing", "x").unwrap();
+    let blank: Option<Issue> = jira.get("api", "/thing").unwrap();
+    jira.post::<EmptyResponse, _>("agile", "/thing", "x").unwrap();
+
+    assert_eq!(unit, ());
+    assert!(none.is_none());
+    assert!(blank.is_none());
+}
+
+#[test]
+fn myself() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "self": "https://example.atlassian.net/rest/api/2/user?accountId=5b10a2844c20165700ede21g",
+            "accountId": "5b10a2844c20165700ede21g",
+            "displayName": "Mia Krystof",
+            "active": true
+        }"#,
+    )]);
+
+    let user = jira(&server).myself().unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/myself");
+    assert_eq!(user.account_id, Some("5b10a2844c20165700ede21g".to_owned()));
+    assert_eq!(user.display_name, "Mia Krystof");
+}
+
+#[test]
+fn my_permissions() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"permissions": {
+            "CREATE_ISSUES": {
+                "id": "11",
+                "key": "CREATE_ISSUES",
+                "name": "Create Issues",
+                "type": "PROJECT",
+                "description": "Ability to create issues.",
+                "havePermission": true
+            },
+            "ADMINISTER": {
+                "id": "0",
+                "key": "ADMINISTER",
+                "name": "Administer Jira",
+                "type": "GLOBAL",
+                "havePermission": false
+            }
+        }}"#,
+    )]);
+
+    let permissions = jira(&server)
+        .my_permissions(&["CREATE_ISSUES", "ADMINISTER"])
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/api/latest/mypermissions");
+    assert_eq!(
+        request.query("permissions"),
+        Some("CREATE_ISSUES,ADMINISTER".to_owned())
+    );
+    assert!(permissions["CREATE_ISSUES"].have_permission);
+    assert!(!permissions["ADMINISTER"].have_permission);
+}
+
+#[test]
+fn host_context_path_is_preserved() {
+    let server = MockServer::start(vec![
+        Response::json(200, ISSUE),
+        Response::json(200, ISSUE),
+    ]);
+    let credentials = Credentials::Basic("user".to_owned(), "pwd".to_owned());
+
+    Jira::new(format!("{}/jira", server.url()), credentials.clone())
+        .unwrap()
+        .issues()
+        .get("ABC-1")
+        .unwrap();
+    Jira::new(format!("{}/jira/", server.url()), credentials)
+        .unwrap()
+        .issues()
+        .get("ABC-1")
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/jira/rest/api/latest/issue/ABC-1");
+    assert_eq!(requests[1].path, "/jira/rest/api/latest/issue/ABC-1");
+}
+
+#[test]
+fn api_version_selects_url_segment() {
+    let versions = vec![
+        (None, "/rest/api/latest/issue/ABC-1"),
+        (Some(ApiVersion::Latest), "/rest/api/latest/issue/ABC-1"),
+        (Some(ApiVersion::V2), "/rest/api/2/issue/ABC-1"),
+        (Some(ApiVersion::V3), "/rest/api/3/issue/ABC-1"),
+    ];
+    for (version, path) in versions {
+        let server = MockServer::start(vec![
+            Response::json(200, ISSUE),
+            Response::json(
+                200,
+                r#"{"maxResults": 50, "startAt": 0, "isLast": true, "values": []}"#,
+            ),
+        ]);
+        let mut builder = Jira::builder(
+            server.url(),
+            Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+        );
+        if let Some(version) = version {
+            builder.api_version(version);
+        }
+        let jira = builder.build().unwrap();
+
+        jira.issues().get("ABC-1").unwrap();
+        jira.boards().list(&Default::default()).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, path);
+        // the agile api is versioned separately
+        assert!(requests[1].path.starts_with("/rest/agile/latest/board"));
+    }
+}
+
+#[test]
+fn list_issue_types() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"[{
+            "self": "http://jira.com/rest/api/2/issuetype/3",
+            "id": "3",
+            "description": "A task that needs to be done.",
+            "iconUrl": "http://jira.com/images/icons/issuetypes/task.png",
+            "name": "Task",
+            "subtask": false
+        }, {
+            "self": "http://jira.com/rest/api/2/issuetype/5",
+            "id": "5",
+            "description": "A small piece of work.",
+            "iconUrl": "http://jira.com/images/icons/issuetypes/subtask.png",
+            "name": "Sub-task",
+            "subtask": true
+        }]"#,
+    )]);
+
+    let types = jira(&server).issue_types().unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/issuetype");
+    assert_eq!(types[1].id, "5");
+    assert_eq!(types[1].name, "Sub-task");
+    assert!(types[1].subtask);
+}
+
+#[test]
+fn list_priorities() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r##"[{
+            "self": "http://jira.com/rest/api/2/priority/1",
+            "statusColor": "#cc0000",
+            "description": "Blocks development and/or testing work.",
+            "iconUrl": "http://jira.com/images/icons/priorities/blocker.svg",
+            "name": "Blocker",
+            "id": "1"
+        }]"##,
+    )]);
+
+    let priorities = jira(&server).priorities().unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/priority");
+    assert_eq!(priorities[0].id, "1");
+    assert_eq!(priorities[0].name, "Blocker");
+}
+
+const PRIORITIES: &str = r#"[{"self": "http://jira.com/rest/api/2/priority/1", "name": "Blocker", "id": "1"}]"#;
+
+#[test]
+fn caches_reference_data() {
+    let server = MockServer::start(vec![
+        Response::json(200, PRIORITIES),
+        Response::json(200, ISSUE),
+        Response::json(200, ISSUE),
+    ]);
+    let jira = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .cache(Duration::from_secs(60))
+    .build()
+    .unwrap();
+
+    jira.priorities().unwrap();
+    let priorities = jira.clone().priorities();
+    jira.issues().get("TEST-1").unwrap();
+    jira.issues().get("TEST-1").unwrap();
+
+    assert_eq!(priorities.unwrap()[0].name, "Blocker");
+    let paths = server
+        .requests()
+        .into_iter()
+        .map(|request| request.path)
+        .collect::<Vec<String>>();
+    assert_eq!(
+        paths,
+        vec![
+            "/rest/api/latest/priority",
+            "/rest/api/latest/issue/TEST-1",
+            "/rest/api/latest/issue/TEST-1",
+        ]
+    );
+}
+
+#[test]
+fn refetches_expired_reference_data() {
+    let server = MockServer::start(vec![
+        Response::json(200, PRIORITIES),
+        Response::json(200, PRIORITIES),
+    ]);
+    let jira = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .cache(Duration::from_millis(0))
+    .build()
+    .unwrap();
+
+    jira.priorities().unwrap();
+    jira.priorities().unwrap();
+
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn list_statuses() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"[{
+            "self": "http://jira.com/rest/api/2/status/10000",
+            "description": "The issue is open and ready for the assignee to start work on it.",
+            "iconUrl": "http://jira.com/images/icons/statuses/open.png",
+            "name": "Open",
+            "id": "10000",
+            "statusCategory": {"id": 2, "key": "new", "colorName": "blue-gray", "name": "To Do"}
+        }]"#,
+    )]);
+
+    let statuses = jira(&server).statuses().unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/status");
+    assert_eq!(statuses[0].name, "Open");
+    assert_eq!(statuses[0].status_category.as_ref().unwrap().key, "new");
+}
+
+#[test]
+fn untyped_json_requests() {
+    let server = MockServer::start(vec![
+        Response::json(
+            200,
+            r#"{"dashboards": [{"id": "10000", "name": "System Dashboard", "popularity": 1}]}"#,
+        ),
+        Response::json(201, r#"{"id": "10001", "nested": {"ok": true}}"#),
+        Response::json(404, r#"{"errorMessages": ["nope"], "errors": {}}"#),
+    ]);
+    let jira = jira(&server);
+
+    let dashboards = jira.get_json("api", "/dashboard").unwrap();
+    let created = jira
+        .post_json(
+            "api",
+            "/dashboard",
+            &serde_json::json!({ "name": "mine", "sharePermissions": [] }),
+        )
+        .unwrap();
+    let missing = jira.get_json("api", "/dashboard/404");
+
+    assert_eq!(dashboards["dashboards"][0]["name"], "System Dashboard");
+    assert_eq!(created["nested"]["ok"], true);
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/rest/api/latest/dashboard");
+    assert_eq!(requests[1].method, "POST");
+    assert_eq!(
+        requests[1].json(),
+        serde_json::json!({ "name": "mine", "sharePermissions": [] })
+    );
+    // responses are still interpreted as errors in the usual way
+    assert!(missing.is_err());
+}
+
+fn gzipped(body: &str) -> Vec<u8> {
+    use std::io::Write;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(body.as_bytes()).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn gzip_responses_are_decompressed() {
+    let server = MockServer::start(vec![
+        Response::json(200, ISSUE)
+            .header("Content-Encoding", "gzip")
+            .body(gzipped(ISSUE)),
+        Response::json(200, ISSUE)
+            .header("Content-Encoding", "gzip")
+            .body(gzipped(ISSUE))
+            .chunked(),
+    ]);
+    let jira = jira(&server);
+
+    let issue = jira.issues().get("TEST-1").unwrap();
+    let chunked = jira.issues().get("TEST-1").unwrap();
+
+    assert_eq!(issue.key, "TEST-1");
+    assert_eq!(chunked.key, "TEST-1");
+    for request in server.requests() {
+        assert!(request.header("Accept-Encoding").unwrap().contains("gzip"));
+    }
+}
+
+#[test]
+fn gzip_can_be_disabled() {
+    let server = MockServer::start(vec![Response::json(200, ISSUE)]);
+    let jira = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .gzip(false)
+    .build()
+    .unwrap();
+
+    jira.issues().get("TEST-1").unwrap();
+
+    let requests = server.requests();
+    assert!(!requests[0]
+        .header("Accept-Encoding")
+        .unwrap_or_default()
+        .contains("gzip"));
+}
+
+#[test]
+fn user_agent() {
+    let server = MockServer::start(vec![
+        Response::json(200, ISSUE),
+        Response::json(200, ISSUE),
+    ]);
+    let credentials = Credentials::Basic("user".to_owned(), "pwd".to_owned());
+
+    Jira::new(server.url(), credentials.clone())
+        .unwrap()
+        .issues()
+        .get("TEST-1")
+        .unwrap();
+    Jira::builder(server.url(), credentials)
+        .user_agent("release-bot/1.0")
+        .build()
+        .unwrap()
+        .issues()
+        .get("TEST-1")
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].header("User-Agent"),
+        Some(concat!("jira-rs/", env!("CARGO_PKG_VERSION")))
+    );
+    assert_eq!(requests[1].header("User-Agent"), Some("release-bot/1.0"));
+}
+
+#[test]
+fn suggest_labels() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"results": [
+            {"value": "backend", "displayName": "<b>back</b>end"},
+            {"value": "backlog", "displayName": "<b>back</b>log"}
+        ]}"#,
+    )]);
+
+    let labels = jira(&server).label_suggestions("back").unwrap();
+
+    assert_eq!(labels, vec!["backend", "backlog"]);
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.path_only(),
+        "/rest/api/latest/jql/autocompletedata/suggestions"
+    );
+    assert_eq!(request.query("fieldName"), Some("labels".to_owned()));
+    assert_eq!(request.query("fieldValue"), Some("back".to_owned()));
+}
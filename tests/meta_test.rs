@@ -0,0 +1,185 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+const CREATE_META: &str = r#"{
+    "expand": "projects",
+    "projects": [{
+        "self": "http://jira.com/rest/api/2/project/10000",
+        "id": "10000",
+        "key": "TEST",
+        "name": "Test Project",
+        "issuetypes": [{
+            "self": "http://jira.com/rest/api/2/issuetype/10004",
+            "id": "10004",
+            "description": "A problem which impairs or prevents the functions of the product.",
+            "name": "Bug",
+            "subtask": false,
+            "fields": {
+                "summary": {
+                    "required": true,
+                    "schema": {"type": "string", "system": "summary"},
+                    "name": "Summary",
+                    "key": "summary",
+                    "hasDefaultValue": false,
+                    "operations": ["set"]
+                },
+                "priority": {
+                    "required": false,
+                    "schema": {"type": "priority", "system": "priority"},
+                    "name": "Priority",
+                    "key": "priority",
+                    "hasDefaultValue": true,
+                    "operations": ["set"],
+                    "allowedValues": [
+                        {"self": "http://jira.com/rest/api/2/priority/1", "name": "Highest", "id": "1"},
+                        {"self": "http://jira.com/rest/api/2/priority/2", "name": "High", "id": "2"}
+                    ]
+                },
+                "customfield_10010": {
+                    "required": true,
+                    "schema": {
+                        "type": "array",
+                        "items": "string",
+                        "custom": "com.atlassian.jira.plugin.system.customfieldtypes:multiselect",
+                        "customId": 10010
+                    },
+                    "name": "Team",
+                    "key": "customfield_10010",
+                    "operations": ["add", "set", "remove"]
+                }
+            }
+        }]
+    }]
+}"#;
+
+#[test]
+fn deserialise_create_meta() {
+    let meta: CreateMeta = serde_json::from_str(CREATE_META).unwrap();
+
+    let bug = &meta.projects[0].issuetypes[0];
+    assert_eq!(bug.name, "Bug");
+    assert!(bug.fields["summary"].required);
+    assert!(!bug.fields["priority"].required);
+    assert_eq!(bug.fields["priority"].allowed_values.len(), 2);
+    let team = &bug.fields["customfield_10010"];
+    assert!(team.required);
+    assert_eq!(team.schema.custom_id, Some(10010));
+    assert_eq!(team.schema.items, Some("string".to_owned()));
+}
+
+#[test]
+fn create_meta_query() {
+    let server = MockServer::start(vec![Response::json(200, CREATE_META)]);
+
+    jira(&server)
+        .issues()
+        .create_meta(
+            &SearchOptions::builder()
+                .project_keys(vec!["TEST", "OTHER"])
+                .issue_type_names(vec!["Bug"])
+                .build(),
+        )
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/api/latest/issue/createmeta");
+    assert_eq!(request.query("projectKeys"), Some("TEST,OTHER".to_owned()));
+    assert_eq!(request.query("issuetypeNames"), Some("Bug".to_owned()));
+    assert_eq!(
+        request.query("expand"),
+        Some("projects.issuetypes.fields".to_owned())
+    );
+}
+
+#[test]
+fn validate_against_reports_missing_required_fields() {
+    let meta: CreateMeta = serde_json::from_str(CREATE_META).unwrap();
+    let mut builder = CreateIssue::builder();
+    builder.project("TEST").summary("a bug").issue_type("10004");
+
+    match builder.validate_against(&meta) {
+        Err(Error::MissingRequiredFields(fields)) => assert_eq!(fields, vec!["Team"]),
+        other => panic!("expected missing fields, got {:?}", other.map(|_| ())),
+    }
+
+    let issue = builder
+        .custom_field("customfield_10010", vec!["backend"])
+        .validate_against(&meta)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(issue.fields.summary, "a bug");
+}
+
+#[test]
+fn validate_against_rejects_unknown_issue_types() {
+    let meta: CreateMeta = serde_json::from_str(CREATE_META).unwrap();
+
+    let result = CreateIssue::builder()
+        .project("TEST")
+        .summary("a story")
+        .issue_type("10001")
+        .validate_against(&meta)
+        .map(|_| ());
+
+    match result {
+        Err(Error::InvalidIssueType {
+            project,
+            issue_type,
+        }) => {
+            assert_eq!(project, "TEST");
+            assert_eq!(issue_type, "10001");
+        }
+        other => panic!("expected an invalid issue type, got {:?}", other),
+    }
+}
+
+#[test]
+fn edit_meta() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"fields": {
+            "summary": {
+                "required": true,
+                "schema": {"type": "string", "system": "summary"},
+                "name": "Summary",
+                "key": "summary",
+                "operations": ["set"]
+            },
+            "labels": {
+                "required": false,
+                "schema": {"type": "array", "items": "string", "system": "labels"},
+                "name": "Labels",
+                "key": "labels",
+                "autoCompleteUrl": "http://jira.com/rest/api/1.0/labels/suggest?query=",
+                "operations": ["add", "remove"]
+            },
+            "priority": {
+                "required": false,
+                "schema": {"type": "priority", "system": "priority"},
+                "name": "Priority",
+                "operations": ["set"],
+                "allowedValues": [{"id": "1", "name": "Blocker"}, {"id": "3", "name": "Major"}]
+            }
+        }}"#,
+    )]);
+
+    let meta = jira(&server).issues().edit_meta("TEST-1").unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/issue/TEST-1/editmeta"
+    );
+    let labels = &meta.fields["labels"];
+    assert!(!labels.required);
+    assert_eq!(labels.operations, vec!["add", "remove"]);
+    assert_eq!(labels.schema.items, Some("string".to_owned()));
+    assert!(meta.fields["summary"].required);
+    assert_eq!(meta.fields["priority"].allowed_values[1]["name"], "Major");
+}
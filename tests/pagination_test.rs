@@ -0,0 +1,114 @@
+extern crate jira_rs;
+extern crate serde_json;
+
+use std::cell::RefCell;
+
+use jira_rs::{PageIter, Paginated, SearchOptions};
+
+/// serves `values` in pages of `size`, recording the offsets requested
+fn source<'a>(
+    values: &'a [u64],
+    size: usize,
+    requested: &'a RefCell<Vec<u64>>,
+) -> impl Fn(&SearchOptions) -> jira_rs::Result<Paginated<u64>> + 'a {
+    move |options| {
+        let start_at = options
+            .serialize()
+            .and_then(|query| {
+                query
+                    .split('&')
+                    .find(|pair| pair.starts_with("startAt="))
+                    .map(|pair| pair["startAt=".len()..].parse::<u64>().unwrap())
+            })
+            .unwrap_or(0);
+        requested.borrow_mut().push(start_at);
+        let page = values
+            .iter()
+            .skip(start_at as usize)
+            .take(size)
+            .cloned()
+            .collect::<Vec<u64>>();
+        Ok(serde_json::from_value(serde_json::json!({
+            "maxResults": size,
+            "startAt": start_at,
+            "total": values.len(),
+            "values": page,
+        }))
+        .unwrap())
+    }
+}
+
+#[test]
+fn iterates_every_page_in_order() {
+    let values = [1, 2, 3, 4, 5];
+    let requested = RefCell::new(vec![]);
+
+    let iter = PageIter::new(&SearchOptions::default(), source(&values, 2, &requested));
+
+    assert_eq!(
+        iter.collect::<jira_rs::Result<Vec<u64>>>().unwrap(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert_eq!(*requested.borrow(), vec![0, 2, 4]);
+}
+
+#[test]
+fn stops_at_last_page() {
+    let values = [1, 2, 3, 4];
+    let requested = RefCell::new(vec![]);
+
+    let iter = PageIter::new(&SearchOptions::default(), source(&values, 2, &requested));
+
+    assert_eq!(iter.count(), 4);
+    assert_eq!(*requested.borrow(), vec![0, 2]);
+}
+
+#[test]
+fn follows_is_last_without_total() {
+    let pages = RefCell::new(vec![
+        r#"{"maxResults": 2, "startAt": 2, "isLast": true, "values": [3]}"#,
+        r#"{"maxResults": 2, "startAt": 0, "isLast": false, "values": [1, 2]}"#,
+    ]);
+
+    let iter = PageIter::new(&SearchOptions::default(), |_: &SearchOptions| {
+        Ok(serde_json::from_str::<Paginated<u64>>(pages.borrow_mut().pop().unwrap()).unwrap())
+    });
+
+    assert_eq!(
+        iter.collect::<jira_rs::Result<Vec<u64>>>().unwrap(),
+        vec![1, 2, 3]
+    );
+    assert!(pages.borrow().is_empty());
+}
+
+#[test]
+fn fetches_nothing_until_advanced() {
+    let values = [1, 2, 3];
+    let requested = RefCell::new(vec![]);
+
+    let mut iter = PageIter::new(&SearchOptions::default(), source(&values, 2, &requested));
+
+    assert!(requested.borrow().is_empty());
+    assert_eq!(iter.next().unwrap().unwrap(), 1);
+    assert_eq!(*requested.borrow(), vec![0]);
+}
+
+#[test]
+fn yields_fetch_errors_then_stops() {
+    let calls = RefCell::new(0);
+
+    let iter = PageIter::new(&SearchOptions::default(), |_: &SearchOptions| {
+        *calls.borrow_mut() += 1;
+        Err::<Paginated<u64>, _>(jira_rs::Error::Unauthorized {
+            errors: Default::default(),
+        })
+    });
+
+    let items = iter.collect::<Vec<_>>();
+    assert_eq!(items.len(), 1);
+    match items[0] {
+        Err(jira_rs::Error::Unauthorized { .. }) => (),
+        ref other => panic!("expected an error, got {:?}", other),
+    }
+    assert_eq!(*calls.borrow(), 1);
+}
@@ -0,0 +1,223 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::{AddRoleActors, Project};
+use support::{jira, MockServer, Response};
+
+#[test]
+fn deserialise_project_list() {
+    let projects: Vec<Project> = serde_json::from_str(
+        r#"[
+        {
+            "self": "http://jira.com/rest/api/2/project/10000",
+            "id": "10000",
+            "key": "EX",
+            "name": "Example",
+            "projectTypeKey": "software",
+            "avatarUrls": {}
+        },
+        {
+            "self": "http://jira.com/rest/api/2/project/10001",
+            "id": "10001",
+            "key": "ABC",
+            "name": "Alphabetical",
+            "projectTypeKey": "business"
+        }
+    ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0].key, "EX");
+    assert_eq!(projects[0].name, "Example");
+    assert_eq!(projects[0].project_type_key, Some("software".to_owned()));
+    assert_eq!(projects[1].key, "ABC");
+    assert_eq!(projects[1].name, "Alphabetical");
+    assert!(projects[1].lead.is_none());
+}
+
+#[test]
+fn get_project_with_lead() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "self": "http://jira.com/rest/api/2/project/EX",
+            "id": "10000",
+            "key": "EX",
+            "name": "Example",
+            "description": "An example project",
+            "projectTypeKey": "software",
+            "lead": {
+                "self": "http://jira.com/rest/api/2/user?username=fred",
+                "name": "fred",
+                "displayName": "Fred F. User",
+                "emailAddress": "fred@example.com",
+                "avatarUrls": {},
+                "active": true
+            }
+        }"#,
+    )]);
+
+    let project = jira(&server).projects().get("EX").unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/project/EX");
+    assert_eq!(project.key, "EX");
+    assert_eq!(project.description, Some("An example project".to_owned()));
+    assert_eq!(project.lead.unwrap().name, Some("fred".to_owned()));
+}
+
+#[test]
+fn list_projects() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"[{"id": "10000", "key": "EX", "name": "Example"}]"#,
+    )]);
+
+    let projects = jira(&server).projects().list().unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/project");
+    assert_eq!(projects[0].key, "EX");
+}
+
+const ROLE: &str = r#"{
+    "self": "http://jira.com/rest/api/2/project/EX/role/10360",
+    "name": "Developers",
+    "id": 10360,
+    "description": "A project role that represents developers in a project",
+    "actors": [
+        {
+            "id": 10240,
+            "displayName": "jira-developers",
+            "type": "atlassian-group-role-actor",
+            "name": "jira-developers",
+            "avatarUrl": "http://jira.com/secure/useravatar?avatarId=10123"
+        },
+        {
+            "id": 10241,
+            "displayName": "Mia Krystof",
+            "type": "atlassian-user-role-actor",
+            "name": "mia"
+        }
+    ]
+}"#;
+
+#[test]
+fn list_project_roles() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "Administrators": "http://jira.com/rest/api/2/project/EX/role/10002",
+            "Developers": "http://jira.com/rest/api/2/project/EX/role/10360"
+        }"#,
+    )]);
+
+    let roles = jira(&server).projects().roles("EX").unwrap();
+
+    assert_eq!(server.requests()[0].path, "/rest/api/latest/project/EX/role");
+    assert_eq!(
+        roles["Developers"],
+        "http://jira.com/rest/api/2/project/EX/role/10360"
+    );
+}
+
+#[test]
+fn get_role_with_user_and_group_actors() {
+    let server = MockServer::start(vec![Response::json(200, ROLE)]);
+
+    let role = jira(&server).projects().role("EX", 10360).unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/project/EX/role/10360"
+    );
+    assert_eq!(role.name, "Developers");
+    assert_eq!(role.actors.len(), 2);
+    assert!(role.actors[0].is_group());
+    assert_eq!(role.actors[0].name, Some("jira-developers".to_owned()));
+    assert!(!role.actors[1].is_group());
+    assert_eq!(role.actors[1].display_name, "Mia Krystof");
+}
+
+#[test]
+fn add_role_actors() {
+    let server = MockServer::start(vec![Response::json(200, ROLE)]);
+
+    let actors = AddRoleActors {
+        group: vec!["jira-developers".to_owned()],
+        ..Default::default()
+    };
+    jira(&server)
+        .projects()
+        .add_role_actors("EX", 10360, actors)
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/project/EX/role/10360");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({ "group": ["jira-developers"] })
+    );
+}
+
+#[test]
+fn list_project_statuses() {
+    let status = |id: &str, name: &str| {
+        format!(
+            r#"{{
+                "self": "http://jira.com/rest/api/2/status/{0}",
+                "description": "",
+                "iconUrl": "http://jira.com/images/icons/statuses/generic.png",
+                "id": "{0}",
+                "name": "{1}"
+            }}"#,
+            id, name
+        )
+    };
+    let server = MockServer::start(vec![Response::json(
+        200,
+        &format!(
+            r#"[
+                {{
+                    "self": "http://jira.com/rest/api/2/issuetype/10001",
+                    "id": "10001",
+                    "name": "Task",
+                    "subtask": false,
+                    "statuses": [{}, {}]
+                }},
+                {{
+                    "self": "http://jira.com/rest/api/2/issuetype/10004",
+                    "id": "10004",
+                    "name": "Bug",
+                    "subtask": false,
+                    "statuses": [{}, {}, {}]
+                }}
+            ]"#,
+            status("1", "Open"),
+            status("6", "Closed"),
+            status("1", "Open"),
+            status("10002", "Triaged"),
+            status("6", "Closed")
+        ),
+    )]);
+
+    let issue_types = jira(&server).projects().statuses("EX").unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/project/EX/statuses"
+    );
+    assert_eq!(issue_types.len(), 2);
+    assert_eq!(issue_types[0].name, "Task");
+    assert_eq!(issue_types[0].statuses.len(), 2);
+    assert_eq!(issue_types[1].name, "Bug");
+    let bug_statuses = issue_types[1]
+        .statuses
+        .iter()
+        .map(|status| status.name.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(bug_statuses, vec!["Open", "Triaged", "Closed"]);
+}
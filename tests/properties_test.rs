@@ -0,0 +1,78 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use serde_json::json;
+use support::{jira, MockServer, Response};
+
+#[test]
+fn property_round_trips_nested_json() {
+    let value = json!({
+        "reviewers": [{"name": "fred", "approved": true}],
+        "build": {"number": 42, "tags": ["nightly"], "artifact": null}
+    });
+    let server = MockServer::start(vec![
+        Response::new(201),
+        Response::json(
+            200,
+            &json!({"key": "ci.status", "value": value.clone()}).to_string(),
+        ),
+    ]);
+    let property = jira(&server).issues().property("TEST-1", "ci.status");
+
+    property.set(&value).unwrap();
+    let fetched = property.get().unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "PUT");
+    assert_eq!(
+        requests[0].path,
+        "/rest/api/latest/issue/TEST-1/properties/ci.status"
+    );
+    assert_eq!(requests[0].json(), value);
+    assert_eq!(requests[1].method, "GET");
+    assert_eq!(fetched, value);
+}
+
+#[test]
+fn delete_property() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .property("TEST-1", "ci.status")
+        .delete()
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "DELETE");
+    assert_eq!(
+        request.path,
+        "/rest/api/latest/issue/TEST-1/properties/ci.status"
+    );
+}
+
+#[test]
+fn property_keys() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"keys": [
+            {"self": "http://jira.com/rest/api/2/issue/TEST-1/properties/ci.status", "key": "ci.status"},
+            {"self": "http://jira.com/rest/api/2/issue/TEST-1/properties/review", "key": "review"}
+        ]}"#,
+    )]);
+
+    let keys = jira(&server)
+        .issues()
+        .property("TEST-1", "ci.status")
+        .keys()
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/issue/TEST-1/properties"
+    );
+    assert_eq!(keys, vec!["ci.status".to_owned(), "review".to_owned()]);
+}
@@ -0,0 +1,108 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+const LINK_ID: &str = r#"{"id": 10000, "self": "http://jira.com/rest/api/2/issue/TEST-1/remotelink/10000"}"#;
+
+#[test]
+fn list_remote_links() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"[{
+            "id": 10000,
+            "self": "http://jira.com/rest/api/2/issue/TEST-1/remotelink/10000",
+            "globalId": "system=http://www.mycompany.com/support&id=1",
+            "relationship": "causes",
+            "object": {
+                "url": "http://www.mycompany.com/support?id=1",
+                "title": "TSTSUP-111",
+                "summary": "Crazy customer support issue"
+            }
+        }]"#,
+    )]);
+
+    let links = jira(&server).issues().remote_links("TEST-1").list().unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/issue/TEST-1/remotelink"
+    );
+    assert_eq!(links[0].id, 10000);
+    assert_eq!(links[0].relationship, Some("causes".to_owned()));
+    assert_eq!(links[0].object.title, "TSTSUP-111");
+}
+
+#[test]
+fn create_remote_link() {
+    let server = MockServer::start(vec![Response::json(201, LINK_ID)]);
+
+    let mut link = CreateRemoteLink::new("http://www.mycompany.com/support?id=1", "TSTSUP-111");
+    link.global_id = Some("system=http://www.mycompany.com/support&id=1".to_owned());
+    let created = jira(&server)
+        .issues()
+        .remote_links("TEST-1")
+        .create(link)
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/remotelink");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "globalId": "system=http://www.mycompany.com/support&id=1",
+            "object": {
+                "url": "http://www.mycompany.com/support?id=1",
+                "title": "TSTSUP-111"
+            }
+        })
+    );
+    assert_eq!(created.id, 10000);
+}
+
+#[test]
+fn create_with_existing_global_id_updates() {
+    // jira answers a repeated global id with the existing link, rather than a new one
+    let server = MockServer::start(vec![
+        Response::json(201, LINK_ID),
+        Response::json(200, LINK_ID),
+    ]);
+    let links = jira(&server).issues().remote_links("TEST-1");
+    let link = |title: &str| {
+        let mut link = CreateRemoteLink::new("http://www.mycompany.com/support?id=1", title);
+        link.global_id = Some("support-1".to_owned());
+        link
+    };
+
+    let created = links.create(link("first title")).unwrap();
+    let updated = links.create(link("second title")).unwrap();
+
+    assert_eq!(created.id, updated.id);
+    let requests = server.requests();
+    assert_eq!(requests[0].json()["globalId"], "support-1");
+    assert_eq!(requests[1].json()["globalId"], "support-1");
+    assert_eq!(requests[1].json()["object"]["title"], "second title");
+}
+
+#[test]
+fn delete_remote_link() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .remote_links("TEST-1")
+        .delete(10000)
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "DELETE");
+    assert_eq!(
+        request.path,
+        "/rest/api/latest/issue/TEST-1/remotelink/10000"
+    );
+}
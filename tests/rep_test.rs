@@ -1,7 +1,7 @@
-extern crate goji;
+extern crate jira_rs;
 extern crate serde_json;
 
-use goji::*;
+use jira_rs::*;
 
 const JIRA_HOST: &str = "http://jira.com";
 
@@ -0,0 +1,145 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use std::time::Duration;
+use support::{MockServer, Response};
+
+const ISSUE: &str = r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#;
+
+fn jira(server: &MockServer, retries: u32) -> Jira {
+    Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .max_retries(retries)
+    .build()
+    .unwrap()
+}
+
+fn rate_limited(retry_after: &str) -> Response {
+    Response::new(429).header("Retry-After", retry_after)
+}
+
+#[test]
+fn retries_rate_limited_requests() {
+    let server = MockServer::start(vec![rate_limited("0"), Response::json(200, ISSUE)]);
+
+    let issue = jira(&server, 3).issues().get("TEST-1").unwrap();
+
+    assert_eq!(issue.key, "TEST-1");
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn retries_after_http_date() {
+    let server = MockServer::start(vec![
+        rate_limited("Wed, 21 Oct 2015 07:28:00 GMT"),
+        Response::new(503).header("Retry-After", "0"),
+        Response::json(200, ISSUE),
+    ]);
+
+    jira(&server, 3).issues().get("TEST-1").unwrap();
+
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn gives_up_after_max_retries() {
+    let server = MockServer::start(vec![
+        rate_limited("0"),
+        rate_limited("0"),
+        Response::json(200, ISSUE),
+    ]);
+
+    match jira(&server, 1).issues().get("TEST-1") {
+        Err(Error::Fault { code, .. }) => assert_eq!(code.as_u16(), 429),
+        other => panic!("expected a fault, got {:?}", other),
+    }
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn does_not_retry_by_default() {
+    let server = MockServer::start(vec![rate_limited("0"), Response::json(200, ISSUE)]);
+
+    assert!(jira(&server, 0).issues().get("TEST-1").is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn does_not_retry_posts_unless_allowed() {
+    let created = r#"{"id": "1", "key": "TEST-1", "self": "http://jira.com/rest/api/2/issue/1"}"#;
+    let server = MockServer::start(vec![
+        rate_limited("0"),
+        rate_limited("0"),
+        Response::json(201, created),
+    ]);
+    let create = || CreateIssue {
+        fields: Fields {
+            summary: "summary".to_owned(),
+            ..Default::default()
+        },
+    };
+
+    assert!(jira(&server, 3).issues().create(create()).is_err());
+    assert_eq!(server.requests().len(), 1);
+
+    let retrying = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .max_retries(3)
+    .retry_non_idempotent(true)
+    .build()
+    .unwrap();
+    assert_eq!(retrying.issues().create(create()).unwrap().key, "TEST-1");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn retries_server_errors_with_backoff() {
+    let server = MockServer::start(vec![
+        Response::new(503),
+        Response::new(503),
+        Response::json(200, ISSUE),
+        Response::new(502),
+        Response::new(504),
+        Response::json(200, ISSUE),
+    ]);
+    let jira = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .max_retries(2)
+    .retry_backoff(Duration::from_millis(1), Duration::from_millis(5))
+    .build()
+    .unwrap();
+
+    assert_eq!(jira.issues().get("TEST-1").unwrap().key, "TEST-1");
+    assert_eq!(server.requests().len(), 3);
+    assert_eq!(jira.issues().get("TEST-1").unwrap().key, "TEST-1");
+    assert_eq!(server.requests().len(), 6);
+}
+
+#[test]
+fn gateway_errors_are_not_retried_when_disabled() {
+    let server = MockServer::start(vec![Response::new(502), Response::json(200, ISSUE)]);
+    let jira = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .max_retries(2)
+    .retry_server_errors(false)
+    .build()
+    .unwrap();
+
+    match jira.issues().get("TEST-1") {
+        Err(Error::Fault { code, .. }) => assert_eq!(code.as_u16(), 502),
+        other => panic!("expected a fault, got {:?}", other),
+    }
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,75 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::{Error, SearchOptions};
+use support::{issues_page, jira, MockServer, Response};
+
+#[test]
+fn iter_issues_in_server_order() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 3, &[1, 2]),
+        issues_page(2, 2, 3, &[3]),
+    ]);
+    let options = SearchOptions::default();
+
+    let keys = jira(&server)
+        .search()
+        .iter("project = TEST", &options)
+        .map(|issue| issue.unwrap().key)
+        .collect::<Vec<String>>();
+
+    assert_eq!(keys, vec!["TEST-1", "TEST-2", "TEST-3"]);
+    let requests = server.requests();
+    assert_eq!(requests[0].path_only(), "/rest/api/latest/search");
+    assert_eq!(requests[1].query("jql"), Some("project = TEST".to_owned()));
+    assert_eq!(requests[1].query("startAt"), Some("2".to_owned()));
+}
+
+#[test]
+fn iter_yields_failed_pages_as_errors() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 4, &[1, 2]),
+        Response::json(
+            500,
+            r#"{"errorMessages": ["Internal server error"], "errors": {}}"#,
+        ),
+    ]);
+    let options = SearchOptions::default();
+
+    let results = jira(&server)
+        .search()
+        .iter("project = TEST", &options)
+        .collect::<Vec<_>>();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().key, "TEST-1");
+    assert_eq!(results[1].as_ref().unwrap().key, "TEST-2");
+    match results[2] {
+        Err(Error::Fault { ref code, .. }) => assert_eq!(code.as_u16(), 500),
+        ref other => panic!("expected a fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn iter_page_size_is_capped_by_the_server() {
+    let server = MockServer::start(vec![
+        issues_page(0, 2, 3, &[1, 2]),
+        issues_page(2, 2, 3, &[3]),
+    ]);
+    let options = SearchOptions::builder().max_results(100).build();
+
+    let count = jira(&server)
+        .search()
+        .iter("project = TEST", &options)
+        .count();
+
+    assert_eq!(count, 3);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].query("maxResults"), Some("100".to_owned()));
+    assert_eq!(requests[1].query("maxResults"), Some("2".to_owned()));
+    assert_eq!(requests[1].query("startAt"), Some("2".to_owned()));
+}
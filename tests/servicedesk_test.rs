@@ -0,0 +1,113 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+#[test]
+fn create_customer_request() {
+    let server = MockServer::start(vec![Response::json(
+        201,
+        r#"{
+            "issueId": "107001",
+            "issueKey": "HELPDESK-1",
+            "requestTypeId": "25",
+            "serviceDeskId": "10",
+            "currentStatus": {"status": "Waiting for Support"}
+        }"#,
+    )]);
+
+    let mut request = CreateCustomerRequest::new("10", "25");
+    request
+        .request_field_values
+        .insert("summary".to_owned(), serde_json::json!("Request JSD help"));
+    let created = jira(&server)
+        .service_desk()
+        .create_request(request)
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/servicedeskapi/request");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "serviceDeskId": "10",
+            "requestTypeId": "25",
+            "requestFieldValues": { "summary": "Request JSD help" }
+        })
+    );
+    assert_eq!(created.issue_key, "HELPDESK-1");
+    assert_eq!(created.current_status.unwrap().status, "Waiting for Support");
+}
+
+#[test]
+fn list_request_types() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "size": 1,
+            "start": 0,
+            "limit": 50,
+            "isLastPage": true,
+            "values": [{
+                "id": "11001",
+                "name": "Get IT Help",
+                "description": "Get IT Help",
+                "helpText": "Please tell us clearly the problem you have within 100 words.",
+                "issueTypeId": "12345",
+                "serviceDeskId": "28",
+                "groupIds": ["12"]
+            }]
+        }"#,
+    )]);
+
+    let types = jira(&server).service_desk().request_types(28).unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/servicedeskapi/servicedesk/28/requesttype"
+    );
+    assert!(types.is_last_page);
+    assert_eq!(types.values[0].name, "Get IT Help");
+}
+
+#[test]
+fn add_internal_comment() {
+    let server = MockServer::start(vec![Response::json(
+        201,
+        r#"{"id": "1000", "body": "Hello there", "public": false}"#,
+    )]);
+
+    let comment = jira(&server)
+        .service_desk()
+        .add_comment("HELPDESK-1", "Hello there", false)
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/servicedeskapi/request/HELPDESK-1/comment");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({ "body": "Hello there", "public": false })
+    );
+    assert!(!comment.public);
+}
+
+#[test]
+fn add_public_comment() {
+    let server = MockServer::start(vec![Response::json(
+        201,
+        r#"{"id": "1001", "body": "Hello there", "public": true}"#,
+    )]);
+
+    jira(&server)
+        .service_desk()
+        .add_comment("HELPDESK-1", "Hello there", true)
+        .unwrap();
+
+    assert_eq!(server.requests()[0].json()["public"], true);
+}
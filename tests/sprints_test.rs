@@ -1,7 +1,12 @@
-extern crate goji;
+extern crate jira_rs;
 extern crate serde_json;
+extern crate url;
 
-use goji::sprints::*;
+mod support;
+
+use jira_rs::sprints::*;
+use jira_rs::{Error, Paginated, SearchOptions};
+use support::{board, jira, MockServer, Response};
 
 #[test]
 fn deserialise_sprint() {
@@ -77,10 +82,221 @@ fn deserialise_sprint_results() {
         }]
     }"#;
 
-    let sprint_results: SprintResults = serde_json::from_str(sprint_results_str).unwrap();
+    let sprint_results: Paginated<Sprint> = serde_json::from_str(sprint_results_str).unwrap();
 
     assert_eq!(sprint_results.max_results, 50u64);
     assert_eq!(sprint_results.start_at, 0u64);
-    assert_eq!(sprint_results.is_last, true);
+    assert_eq!(sprint_results.is_last, Some(true));
     assert_eq!(sprint_results.values.len(), 1);
 }
+
+#[test]
+fn list_board_sprints() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "maxResults": 50,
+            "startAt": 0,
+            "isLast": true,
+            "values": [{
+                "id": 37,
+                "self": "http://www.example.com/jira/rest/agile/1.0/sprint/37",
+                "state": "active",
+                "name": "sprint 1",
+                "startDate": "2015-04-11T15:22:00.000+10:00",
+                "endDate": "2015-04-20T01:22:00.000+10:00",
+                "originBoardId": 5
+            }]
+        }"#,
+    )]);
+
+    let sprints = jira(&server)
+        .boards()
+        .sprints(5, &SearchOptions::builder().state("active").build())
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/agile/latest/board/5/sprint");
+    assert_eq!(request.query("state"), Some("active".to_owned()));
+    let sprint = &sprints.values[0];
+    assert_eq!(sprint.state, Some("active".to_owned()));
+    assert_eq!(sprint.origin_board_id, Some(5));
+}
+
+fn sprints_page(start_at: u64, max_results: u64, is_last: bool, ids: &[u64]) -> Response {
+    let values = ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"id": {0}, "self": "http://www.example.com/jira/rest/agile/1.0/sprint/{0}", "name": "sprint {0}"}}"#,
+                id
+            )
+        })
+        .collect::<Vec<String>>();
+    Response::json(
+        200,
+        &format!(
+            r#"{{"maxResults": {}, "startAt": {}, "isLast": {}, "values": [{}]}}"#,
+            max_results,
+            start_at,
+            is_last,
+            values.join(",")
+        ),
+    )
+}
+
+#[test]
+fn iter_sprints_in_server_order() {
+    let server = MockServer::start(vec![
+        sprints_page(0, 2, false, &[1, 2]),
+        sprints_page(2, 2, true, &[3]),
+    ]);
+    let board = board();
+    let options = SearchOptions::default();
+
+    let ids = jira(&server)
+        .sprints()
+        .iter(&board, &options)
+        .map(|sprint| sprint.unwrap().id)
+        .collect::<Vec<u64>>();
+
+    assert_eq!(ids, vec![1, 2, 3]);
+    let requests = server.requests();
+    assert_eq!(requests[0].path_only(), "/rest/agile/latest/board/1/sprint");
+    assert_eq!(requests[1].query("startAt"), Some("2".to_owned()));
+}
+
+#[test]
+fn iter_sprints_yields_failed_pages_as_errors() {
+    let server = MockServer::start(vec![
+        sprints_page(0, 2, false, &[1, 2]),
+        Response::json(
+            500,
+            r#"{"errorMessages": ["Internal server error"], "errors": {}}"#,
+        ),
+    ]);
+    let board = board();
+    let options = SearchOptions::default();
+
+    let results = jira(&server)
+        .sprints()
+        .iter(&board, &options)
+        .collect::<Vec<_>>();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().id, 1);
+    assert_eq!(results[1].as_ref().unwrap().id, 2);
+    match results[2] {
+        Err(Error::Fault { ref code, .. }) => assert_eq!(code.as_u16(), 500),
+        ref other => panic!("expected a fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn iter_sprints_continues_after_a_page_cut_short_by_the_server() {
+    let server = MockServer::start(vec![
+        sprints_page(0, 50, false, &[1, 2]),
+        sprints_page(2, 50, true, &[3]),
+    ]);
+    let board = board();
+    let options = SearchOptions::builder().max_results(100).build();
+
+    let ids = jira(&server)
+        .sprints()
+        .iter(&board, &options)
+        .map(|sprint| sprint.unwrap().id)
+        .collect::<Vec<u64>>();
+
+    assert_eq!(ids, vec![1, 2, 3]);
+    let requests = server.requests();
+    assert_eq!(requests[1].query("startAt"), Some("2".to_owned()));
+    assert_eq!(requests[1].query("maxResults"), Some("50".to_owned()));
+}
+
+#[test]
+fn create_future_sprint() {
+    let server = MockServer::start(vec![Response::json(
+        201,
+        r#"{
+            "id": 38,
+            "self": "http://www.example.com/jira/rest/agile/1.0/sprint/38",
+            "state": "future",
+            "name": "sprint 2",
+            "startDate": "2015-04-21T15:22:00.000+10:00",
+            "originBoardId": 5
+        }"#,
+    )]);
+
+    let mut data = CreateSprint::new("sprint 2", 5);
+    data.start_date = Some("2015-04-21T15:22:00.000+10:00".to_owned());
+    let sprint = jira(&server).sprints().create(data).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/agile/latest/sprint");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "name": "sprint 2",
+            "originBoardId": 5,
+            "startDate": "2015-04-21T15:22:00.000+10:00"
+        })
+    );
+    assert_eq!(sprint.id, 38);
+    assert_eq!(sprint.state, Some("future".to_owned()));
+}
+
+#[test]
+fn update_sprint_state() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"id": 38, "self": "http://www.example.com/jira/rest/agile/1.0/sprint/38", "state": "active", "name": "sprint 2"}"#,
+    )]);
+
+    let data = UpdateSprint {
+        state: Some("active".to_owned()),
+        ..Default::default()
+    };
+    jira(&server).sprints().update(38, data).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/agile/latest/sprint/38");
+    assert_eq!(request.json(), serde_json::json!({"state": "active"}));
+}
+
+#[test]
+fn move_issues_into_sprint() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .sprints()
+        .move_issues(38, vec!["ABC-1".to_owned(), "ABC-2".to_owned()])
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/agile/latest/sprint/38/issue");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({ "issues": ["ABC-1", "ABC-2"] })
+    );
+}
+
+#[test]
+fn move_issues_to_backlog_in_chunks() {
+    let server = MockServer::start(vec![Response::new(204), Response::new(204)]);
+    let issues = (1..=75).map(|n| format!("ABC-{}", n)).collect::<Vec<String>>();
+
+    jira(&server).sprints().backlog(issues).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].path, "/rest/agile/latest/backlog/issue");
+    let first = requests[0].json();
+    let second = requests[1].json();
+    assert_eq!(first["issues"].as_array().unwrap().len(), 50);
+    assert_eq!(first["issues"][0], "ABC-1");
+    assert_eq!(second["issues"].as_array().unwrap().len(), 25);
+    assert_eq!(second["issues"][0], "ABC-51");
+}
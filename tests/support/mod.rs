@@ -0,0 +1,286 @@
+//! A minimal scripted http server for exercising the client against canned responses
+
+#![allow(dead_code)]
+
+use jira_rs::{Board, Credentials, Jira};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// a canned response served by a `MockServer`
+#[derive(Clone, Debug)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    pub delay: Option<Duration>,
+    pub chunked: bool,
+}
+
+impl Response {
+    pub fn new(status: u16) -> Response {
+        Response {
+            status,
+            headers: vec![],
+            body: vec![],
+            delay: None,
+            chunked: false,
+        }
+    }
+
+    /// a response with a json body
+    pub fn json(status: u16, body: &str) -> Response {
+        Response::new(status)
+            .header("Content-Type", "application/json;charset=UTF-8")
+            .body(body.as_bytes().to_vec())
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Response {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    pub fn body(mut self, body: Vec<u8>) -> Response {
+        self.body = body;
+        self
+    }
+
+    /// send the body with chunked transfer encoding, rather than a content length
+    pub fn chunked(mut self) -> Response {
+        self.chunked = true;
+        self
+    }
+
+    /// wait before writing the response
+    pub fn delay(mut self, delay: Duration) -> Response {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+/// a request as it was received by a `MockServer`
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// the first value of a header, matched case insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn body_str(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    pub fn json(&self) -> ::serde_json::Value {
+        ::serde_json::from_slice(&self.body).expect("request body to be json")
+    }
+
+    /// the path without its query string
+    pub fn path_only(&self) -> &str {
+        self.path.split('?').next().unwrap_or_default()
+    }
+
+    /// decoded value of a query parameter
+    pub fn query(&self, name: &str) -> Option<String> {
+        let query = self.path.split_once('?')?.1;
+        ::url::form_urlencoded::parse(query.as_bytes())
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    }
+}
+
+/// serves each of a list of responses, in order, to one connection apiece
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<Response>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for response in responses {
+                let (stream, _) = match listener.accept() {
+                    Ok(conn) => conn,
+                    Err(_) => return,
+                };
+                serve(stream, |_| response.clone(), &recorded);
+            }
+        });
+        MockServer { url, requests }
+    }
+
+    /// serves `count` connections, choosing each response from the request received,
+    /// for clients whose requests may arrive in any order
+    pub fn route<F>(count: usize, respond: F) -> MockServer
+    where
+        F: Fn(&Request) -> Response + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for _ in 0..count {
+                let (stream, _) = match listener.accept() {
+                    Ok(conn) => conn,
+                    Err(_) => return,
+                };
+                serve(stream, &respond, &recorded);
+            }
+        });
+        MockServer { url, requests }
+    }
+
+    /// base url of the server, suitable for use as a jira host
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    /// requests received so far
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// a client of a mock server, authenticating with basic credentials
+pub fn jira(server: &MockServer) -> Jira {
+    Jira::new(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .unwrap()
+}
+
+/// a scrum board with id 1
+pub fn board() -> Board {
+    Board {
+        self_link: "http://jira.com/rest/agile/1.0/board/1".to_owned(),
+        id: 1,
+        name: "TEST board".to_owned(),
+        type_name: "scrum".to_owned(),
+    }
+}
+
+/// a page of search results holding the issues `TEST-<id>` for each of `ids`
+pub fn issues_page(start_at: u64, max_results: u64, total: u64, ids: &[u64]) -> Response {
+    let issues = ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"self": "http://jira.com/rest/api/2/issue/{0}", "id": "{0}", "key": "TEST-{0}", "fields": {{}}}}"#,
+                id
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    Response::json(
+        200,
+        &format!(
+            r#"{{"expand": "names,schema", "startAt": {}, "maxResults": {}, "total": {}, "issues": [{}]}}"#,
+            start_at, max_results, total, issues
+        ),
+    )
+}
+
+fn serve<F>(stream: TcpStream, respond: F, recorded: &Mutex<Vec<Request>>) -> Option<()>
+where
+    F: Fn(&Request) -> Response,
+{
+    let mut reader = BufReader::new(stream.try_clone().ok()?);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_owned();
+    let path = parts.next()?.to_owned();
+
+    let mut headers = vec![];
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let mut kv = header.splitn(2, ':');
+        let name = kv.next().unwrap_or_default().trim().to_owned();
+        let value = kv.next().unwrap_or_default().trim().to_owned();
+        headers.push((name, value));
+    }
+
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+    };
+    let mut body = vec![];
+    if let Some(len) = header("content-length").and_then(|len| len.parse::<usize>().ok()) {
+        body.resize(len, 0);
+        reader.read_exact(&mut body).ok()?;
+    } else if header("transfer-encoding").is_some_and(|te| te.contains("chunked")) {
+        loop {
+            let mut size = String::new();
+            reader.read_line(&mut size).ok()?;
+            let size = usize::from_str_radix(size.trim(), 16).ok()?;
+            let mut chunk = vec![0; size + 2];
+            reader.read_exact(&mut chunk).ok()?;
+            if size == 0 {
+                break;
+            }
+            body.extend_from_slice(&chunk[..size]);
+        }
+    }
+
+    let request = Request {
+        method,
+        path,
+        headers,
+        body,
+    };
+    let response = respond(&request);
+    recorded.lock().unwrap().push(request);
+
+    if let Some(delay) = response.delay {
+        thread::sleep(delay);
+    }
+
+    let mut out = stream;
+    let mut head = format!("HTTP/1.1 {} Mock\r\n", response.status);
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if response.chunked {
+        head.push_str("Transfer-Encoding: chunked\r\nConnection: close\r\n\r\n");
+        let _ = out.write_all(head.as_bytes());
+        for chunk in response.body.chunks(16) {
+            let _ = out.write_all(format!("{:x}\r\n", chunk.len()).as_bytes());
+            let _ = out.write_all(chunk);
+            let _ = out.write_all(b"\r\n");
+        }
+        let _ = out.write_all(b"0\r\n\r\n");
+    } else {
+        head.push_str(&format!(
+            "Content-Length: {}\r\nConnection: close\r\n\r\n",
+            response.body.len()
+        ));
+        let _ = out.write_all(head.as_bytes());
+        let _ = out.write_all(&response.body);
+    }
+    let _ = out.flush();
+    Some(())
+}
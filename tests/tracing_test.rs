@@ -0,0 +1,91 @@
+#![cfg(feature = "tracing")]
+
+extern crate jira_rs;
+extern crate serde_json;
+extern crate tracing;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use support::{MockServer, Response};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+/// records the fields of every span and event as `name=value` strings
+#[derive(Clone, Default)]
+struct Capture {
+    fields: Arc<Mutex<Vec<String>>>,
+    ids: Arc<AtomicUsize>,
+}
+
+impl Visit for Capture {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.fields
+            .lock()
+            .unwrap()
+            .push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        *metadata.level() <= Level::TRACE
+    }
+
+    fn new_span(&self, span: &Attributes) -> Id {
+        span.record(&mut self.clone());
+        Id::from_u64(self.ids.fetch_add(1, Ordering::SeqCst) as u64 + 1)
+    }
+
+    fn record(&self, _: &Id, values: &Record) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn requests_are_traced_without_credentials() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"self": "http://jira.com/rest/api/2/issue/1", "id": "1", "key": "TEST-1", "fields": {}}"#,
+    )]);
+    let host = server.url().replace("http://", "http://fred:hunter2@");
+    let jira = Jira::new(
+        host,
+        Credentials::Basic("fred".to_owned(), "hunter2".to_owned()),
+    )
+    .unwrap();
+    let capture = Capture::default();
+
+    tracing::subscriber::with_default(capture.clone(), || {
+        jira.issues().get("TEST-1").unwrap();
+    });
+
+    let fields = capture.fields.lock().unwrap();
+    assert!(fields.iter().any(|field| field == "method=GET"));
+    assert!(fields
+        .iter()
+        .any(|field| field.starts_with("url=") && field.contains("/rest/api/latest/issue/TEST-1")));
+    assert!(fields.iter().any(|field| field == "status=200"));
+    assert!(fields.iter().any(|field| field.starts_with("elapsed_ms=")));
+    assert!(fields.iter().any(|field| field.contains("TEST-1") && field.starts_with("body=")));
+    for field in fields.iter() {
+        assert!(!field.contains("hunter2"), "leaked credential in {}", field);
+        // base64 of fred:hunter2
+        assert!(!field.contains("ZnJlZDpodW50ZXIy"), "leaked credential in {}", field);
+    }
+}
@@ -0,0 +1,96 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+#[test]
+fn list_transitions() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "expand": "transitions",
+            "transitions": [{
+                "id": "31",
+                "name": "Done",
+                "to": {
+                    "self": "http://jira.com/rest/api/2/status/10001",
+                    "name": "Done",
+                    "id": "10001"
+                }
+            }]
+        }"#,
+    )]);
+
+    let transitions = jira(&server).issues().transitions("TEST-1").unwrap();
+
+    assert_eq!(transitions.len(), 1);
+    assert_eq!(transitions[0].id, "31");
+    assert_eq!(transitions[0].to.name, "Done");
+    assert_eq!(
+        server.requests()[0].path_only(),
+        "/rest/api/latest/issue/TEST-1/transitions"
+    );
+}
+
+#[test]
+fn trigger_transition_with_resolution() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .transition(
+            "TEST-1",
+            TransitionTriggerOptions::builder("31")
+                .resolution("Fixed")
+                .build(),
+        )
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/transitions");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "transition": { "id": "31" },
+            "fields": { "resolution": { "name": "Fixed" } }
+        })
+    );
+}
+
+#[test]
+fn bulk_transition_collects_failures() {
+    let server = MockServer::start(vec![
+        Response::new(204),
+        Response::json(
+            400,
+            r#"{"errorMessages": ["Transition id '31' is not valid for this issue."], "errors": {}}"#,
+        ),
+        Response::new(204),
+    ]);
+    let keys = vec![
+        "TEST-1".to_owned(),
+        "TEST-2".to_owned(),
+        "TEST-3".to_owned(),
+    ];
+
+    let result = jira(&server)
+        .issues()
+        .bulk_transition(&keys, TransitionTriggerOptions::new("31"))
+        .unwrap();
+
+    assert!(!result.is_success());
+    assert!(result.results["TEST-1"].is_ok());
+    assert!(result.results["TEST-3"].is_ok());
+    let failures = result.failures().collect::<Vec<_>>();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, "TEST-2");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[2].path, "/rest/api/latest/issue/TEST-3/transitions");
+    assert_eq!(requests[2].json()["transition"]["id"], "31");
+}
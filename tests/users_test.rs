@@ -0,0 +1,125 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::{Credentials, Deployment, Jira, SearchOptions};
+use support::{jira, MockServer, Response};
+
+const SERVER_USERS: &str = r#"[{
+    "self": "http://jira.com/rest/api/2/user?username=fred",
+    "key": "fred",
+    "name": "fred",
+    "emailAddress": "fred@example.com",
+    "avatarUrls": {"48x48": "http://jira.com/secure/useravatar?size=large&ownerId=fred"},
+    "displayName": "Fred F. User",
+    "active": true,
+    "timeZone": "Australia/Sydney"
+}]"#;
+
+const CLOUD_USERS: &str = r#"[{
+    "self": "https://example.atlassian.net/rest/api/2/user?accountId=5b10a2844c20165700ede21g",
+    "accountId": "5b10a2844c20165700ede21g",
+    "accountType": "atlassian",
+    "avatarUrls": {},
+    "displayName": "Mia Krystof",
+    "active": true
+}]"#;
+
+#[test]
+fn search_server_users() {
+    let server = MockServer::start(vec![Response::json(200, SERVER_USERS)]);
+
+    let users = jira(&server)
+        .users()
+        .search_by_username("fred", &SearchOptions::builder().max_results(10).build())
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path_only(), "/rest/api/latest/user/search");
+    assert_eq!(request.query("username"), Some("fred".to_owned()));
+    assert_eq!(request.query("maxResults"), Some("10".to_owned()));
+    assert_eq!(users[0].name, Some("fred".to_owned()));
+    assert_eq!(users[0].email_address, Some("fred@example.com".to_owned()));
+    assert_eq!(users[0].account_id, None);
+}
+
+#[test]
+fn search_cloud_users() {
+    let server = MockServer::start(vec![Response::json(200, CLOUD_USERS)]);
+
+    let users = jira(&server)
+        .users()
+        .search("mia", &SearchOptions::default())
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.query("query"), Some("mia".to_owned()));
+    assert_eq!(
+        users[0].account_id,
+        Some("5b10a2844c20165700ede21g".to_owned())
+    );
+    assert_eq!(users[0].display_name, "Mia Krystof");
+    assert!(users[0].active);
+    assert_eq!(users[0].name, None);
+    assert_eq!(users[0].email_address, None);
+}
+
+#[test]
+fn find_users_by_deployment() {
+    let server = MockServer::start(vec![
+        Response::json(200, SERVER_USERS),
+        Response::json(200, CLOUD_USERS),
+    ]);
+    let cloud = Jira::builder(
+        server.url(),
+        Credentials::Basic("user".to_owned(), "pwd".to_owned()),
+    )
+    .deployment(Deployment::Cloud)
+    .build()
+    .unwrap();
+
+    let server_users = jira(&server)
+        .users()
+        .find("fred", &SearchOptions::default())
+        .unwrap();
+    let cloud_users = cloud
+        .users()
+        .find("mia", &SearchOptions::default())
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].query("username"), Some("fred".to_owned()));
+    assert_eq!(requests[0].query("query"), None);
+    assert_eq!(requests[1].query("query"), Some("mia".to_owned()));
+    assert_eq!(requests[1].query("username"), None);
+    let fred = Deployment::Server.assignee(&server_users[0]).unwrap();
+    assert_eq!(fred.name, Some("fred".to_owned()));
+    assert_eq!(fred.account_id, None);
+    let mia = Deployment::Cloud.assignee(&cloud_users[0]).unwrap();
+    assert_eq!(mia.account_id, Some("5b10a2844c20165700ede21g".to_owned()));
+    assert!(Deployment::Server.assignee(&cloud_users[0]).is_none());
+}
+
+#[test]
+fn get_user() {
+    let cloud_user = CLOUD_USERS.trim_start_matches('[').trim_end_matches(']');
+    let server_user = SERVER_USERS.trim_start_matches('[').trim_end_matches(']');
+    let server = MockServer::start(vec![
+        Response::json(200, cloud_user),
+        Response::json(200, server_user),
+    ]);
+    let users = jira(&server).users();
+
+    users.get("5b10a2844c20165700ede21g").unwrap();
+    users.get_by_username("fred").unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path_only(), "/rest/api/latest/user");
+    assert_eq!(
+        requests[0].query("accountId"),
+        Some("5b10a2844c20165700ede21g".to_owned())
+    );
+    assert_eq!(requests[1].query("username"), Some("fred".to_owned()));
+}
@@ -0,0 +1,76 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+const VERSION: &str = r#"{
+    "self": "http://jira.com/rest/api/2/version/10000",
+    "id": "10000",
+    "description": "An excellent version",
+    "name": "New Version 1",
+    "archived": false,
+    "released": true,
+    "releaseDate": "2010-07-06",
+    "projectId": 10000
+}"#;
+
+#[test]
+fn list_project_versions() {
+    let server = MockServer::start(vec![Response::json(200, &format!("[{}]", VERSION))]);
+
+    let versions = jira(&server).projects().versions("TEST").unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/project/TEST/versions"
+    );
+    assert_eq!(versions[0].name, "New Version 1");
+    assert!(versions[0].released);
+    assert_eq!(versions[0].release_date, Some("2010-07-06".to_owned()));
+}
+
+#[test]
+fn create_version_with_release_date() {
+    let server = MockServer::start(vec![Response::json(201, VERSION)]);
+
+    let mut data = CreateVersion::new("New Version 1", "TEST");
+    data.description = Some("An excellent version".to_owned());
+    data.release_date = Some("2010-07-06".to_owned());
+    data.released = true;
+    let version = jira(&server).versions().create(data).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/version");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "name": "New Version 1",
+            "project": "TEST",
+            "description": "An excellent version",
+            "releaseDate": "2010-07-06",
+            "released": true
+        })
+    );
+    assert_eq!(version.id, "10000");
+}
+
+#[test]
+fn update_version() {
+    let server = MockServer::start(vec![Response::json(200, VERSION)]);
+
+    let data = UpdateVersion {
+        released: Some(true),
+        ..Default::default()
+    };
+    jira(&server).versions().update("10000", data).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "PUT");
+    assert_eq!(request.path, "/rest/api/latest/version/10000");
+    assert_eq!(request.json(), serde_json::json!({ "released": true }));
+}
@@ -0,0 +1,74 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+#[test]
+fn get_votes() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/TEST-1/votes",
+            "votes": 1,
+            "hasVoted": true,
+            "voters": [{
+                "self": "http://jira.com/rest/api/2/user?username=fred",
+                "name": "fred",
+                "displayName": "Fred F. User",
+                "active": true
+            }]
+        }"#,
+    )]);
+
+    let votes = jira(&server).issues().votes("TEST-1").get().unwrap();
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/issue/TEST-1/votes"
+    );
+    assert_eq!(votes.votes, 1);
+    assert!(votes.has_voted);
+    assert_eq!(votes.voters[0].display_name, "Fred F. User");
+}
+
+#[test]
+fn cast_and_withdraw_vote() {
+    let server = MockServer::start(vec![Response::new(204), Response::new(204)]);
+    let votes = jira(&server).issues().votes("TEST-1");
+
+    votes.add().unwrap();
+    votes.remove().unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/rest/api/latest/issue/TEST-1/votes");
+    assert!(requests[0].body.is_empty());
+    assert_eq!(requests[1].method, "DELETE");
+    assert_eq!(requests[1].path, "/rest/api/latest/issue/TEST-1/votes");
+}
+
+#[test]
+fn votes_in_issue_fields() {
+    let fields: Fields = serde_json::from_str(
+        r#"{
+            "issuetype": {"id": "10004"},
+            "project": {"key": "TEST"},
+            "summary": "Something is broken",
+            "votes": {
+                "self": "http://jira.com/rest/api/2/issue/TEST-1/votes",
+                "votes": 3,
+                "hasVoted": false
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let votes = fields.votes.as_ref().unwrap();
+    assert_eq!(votes.votes, 3);
+    assert!(votes.voters.is_empty());
+    assert!(serde_json::to_value(&fields).unwrap().get("votes").is_none());
+}
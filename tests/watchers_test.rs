@@ -0,0 +1,61 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use support::{jira, MockServer, Response};
+
+#[test]
+fn list_watchers() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "self": "http://jira.com/rest/api/2/issue/TEST-1/watchers",
+            "isWatching": false,
+            "watchCount": 1,
+            "watchers": [{
+                "self": "http://jira.com/rest/api/2/user?username=fred",
+                "name": "fred",
+                "emailAddress": "fred@example.com",
+                "displayName": "Fred F. User",
+                "active": true,
+                "avatarUrls": {}
+            }]
+        }"#,
+    )]);
+
+    let watchers = jira(&server).issues().watchers("TEST-1").list().unwrap();
+
+    assert_eq!(watchers.watch_count, 1);
+    assert!(!watchers.is_watching);
+    assert_eq!(watchers.watchers[0].name, Some("fred".to_owned()));
+}
+
+#[test]
+fn add_watcher_sends_bare_json_string() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server).issues().watchers("TEST-1").add("fred").unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/watchers");
+    assert_eq!(request.body_str(), r#""fred""#);
+}
+
+#[test]
+fn remove_watcher() {
+    let server = MockServer::start(vec![Response::new(204)]);
+
+    jira(&server)
+        .issues()
+        .watchers("TEST-1")
+        .remove("fred flintstone")
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "DELETE");
+    assert_eq!(request.path_only(), "/rest/api/latest/issue/TEST-1/watchers");
+    assert_eq!(request.query("username"), Some("fred flintstone".to_owned()));
+}
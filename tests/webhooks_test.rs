@@ -0,0 +1,103 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+#[test]
+fn register_webhooks_with_jql_filter() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{"webhookRegistrationResult": [
+            {"createdWebhookId": 1000},
+            {"errors": ["The clause watchCount is unsupported"]}
+        ]}"#,
+    )]);
+
+    let result = jira(&server)
+        .webhooks()
+        .register(RegisterWebhook {
+            url: "https://example.com/webhook".to_owned(),
+            webhooks: vec![
+                WebhookDetails {
+                    jql_filter: "project = TEST".to_owned(),
+                    events: vec!["jira:issue_created".to_owned()],
+                },
+                WebhookDetails {
+                    jql_filter: "watchCount > 1".to_owned(),
+                    events: vec!["jira:issue_updated".to_owned()],
+                },
+            ],
+        })
+        .unwrap();
+
+    let registrations = result.webhook_registration_result;
+    assert_eq!(registrations[0].created_webhook_id, Some(1000));
+    assert_eq!(registrations[1].created_webhook_id, None);
+    assert_eq!(registrations[1].errors.len(), 1);
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/rest/api/latest/webhook");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({
+            "url": "https://example.com/webhook",
+            "webhooks": [
+                { "jqlFilter": "project = TEST", "events": ["jira:issue_created"] },
+                { "jqlFilter": "watchCount > 1", "events": ["jira:issue_updated"] }
+            ]
+        })
+    );
+}
+
+#[test]
+fn list_webhooks() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        r#"{
+            "maxResults": 100,
+            "startAt": 0,
+            "total": 1,
+            "isLast": true,
+            "values": [{
+                "id": 1000,
+                "jqlFilter": "project = TEST",
+                "events": ["jira:issue_created"],
+                "expirationDate": "2019-06-01T12:42:30.000+0000"
+            }]
+        }"#,
+    )]);
+
+    let page = jira(&server).webhooks().list(&Default::default()).unwrap();
+
+    assert_eq!(page.values[0].id, 1000);
+    assert_eq!(page.values[0].jql_filter, "project = TEST");
+    assert_eq!(server.requests()[0].path_only(), "/rest/api/latest/webhook");
+}
+
+#[test]
+fn refresh_and_delete_webhooks() {
+    let server = MockServer::start(vec![
+        Response::json(200, r#"{"expirationDate": "2019-07-01T12:42:30.000+0000"}"#),
+        Response::new(202),
+    ]);
+    let webhooks = jira(&server).webhooks();
+
+    let refreshed = webhooks.refresh(&[1000, 1001]).unwrap();
+    webhooks.delete(&[1000]).unwrap();
+
+    assert_eq!(refreshed.expiration_date, "2019-07-01T12:42:30.000+0000");
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "PUT");
+    assert_eq!(requests[0].path, "/rest/api/latest/webhook/refresh");
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({ "webhookIds": [1000, 1001] })
+    );
+    assert_eq!(requests[1].method, "DELETE");
+    assert_eq!(requests[1].path, "/rest/api/latest/webhook");
+    assert_eq!(requests[1].json(), serde_json::json!({ "webhookIds": [1000] }));
+}
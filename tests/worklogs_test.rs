@@ -0,0 +1,166 @@
+extern crate jira_rs;
+extern crate serde_json;
+extern crate url;
+
+mod support;
+
+use jira_rs::*;
+use support::{jira, MockServer, Response};
+
+const WORKLOG: &str = r#"{
+    "self": "http://jira.com/rest/api/2/issue/10010/worklog/10000",
+    "id": "10000",
+    "comment": "I did some work here.",
+    "started": "2018-07-11T16:56:12.000+0000",
+    "timeSpent": "3h 20m",
+    "timeSpentSeconds": 12000
+}"#;
+
+#[test]
+fn list_worklogs() {
+    let server = MockServer::start(vec![Response::json(
+        200,
+        &format!(
+            r#"{{"startAt": 0, "maxResults": 1, "total": 1, "worklogs": [{}]}}"#,
+            WORKLOG
+        ),
+    )]);
+
+    let worklogs = jira(&server).issues().worklogs("TEST-1").list().unwrap();
+
+    assert_eq!(worklogs.total, 1);
+    assert_eq!(worklogs.values[0].time_spent_seconds, 12000);
+    assert_eq!(
+        worklogs.values[0]
+            .comment
+            .as_ref()
+            .and_then(|comment| comment.as_plain()),
+        Some("I did some work here.")
+    );
+    assert_eq!(
+        server.requests()[0].path,
+        "/rest/api/latest/issue/TEST-1/worklog"
+    );
+}
+
+#[test]
+fn add_worklog() {
+    let server = MockServer::start(vec![Response::json(201, WORKLOG)]);
+
+    let worklog = jira(&server)
+        .issues()
+        .worklogs("TEST-1")
+        .add(AddWorklog {
+            comment: Some("I did some work here.".into()),
+            ..AddWorklog::new(12000)
+        })
+        .unwrap();
+
+    assert_eq!(worklog.id, "10000");
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/rest/api/latest/issue/TEST-1/worklog");
+    assert_eq!(
+        request.json(),
+        serde_json::json!({ "comment": "I did some work here.", "timeSpentSeconds": 12000 })
+    );
+}
+
+#[test]
+fn worklog_document_comments() {
+    let worklog: Worklog = serde_json::from_str(
+        r#"{
+            "self": "http://jira.com/rest/api/3/issue/10010/worklog/10000",
+            "id": "10000",
+            "comment": {
+                "type": "doc",
+                "version": 1,
+                "content": [{
+                    "type": "paragraph",
+                    "content": [{"type": "text", "text": "I did some work here."}]
+                }]
+            },
+            "started": "2018-07-11T16:56:12.000+0000",
+            "timeSpentSeconds": 12000
+        }"#,
+    )
+    .unwrap();
+    let add = AddWorklog {
+        comment: Some(AdfDocument::from_plain("I did some work here.").into()),
+        ..AddWorklog::new(12000)
+    };
+
+    assert_eq!(
+        worklog
+            .comment
+            .as_ref()
+            .and_then(|comment| comment.as_document()),
+        Some(&AdfDocument::from_plain("I did some work here."))
+    );
+    assert_eq!(
+        serde_json::to_value(&add).unwrap()["comment"],
+        serde_json::to_value(AdfDocument::from_plain("I did some work here.")).unwrap()
+    );
+}
+
+#[test]
+fn add_worklog_adjust_estimate() {
+    let variants = vec![
+        (AdjustEstimate::New, "new"),
+        (AdjustEstimate::Leave, "leave"),
+        (AdjustEstimate::Manual, "manual"),
+        (AdjustEstimate::Auto, "auto"),
+    ];
+    let server = MockServer::start(
+        variants
+            .iter()
+            .map(|_| Response::json(201, WORKLOG))
+            .collect(),
+    );
+    let worklogs = jira(&server).issues().worklogs("TEST-1");
+
+    for &(adjust, _) in &variants {
+        worklogs
+            .add(AddWorklog {
+                adjust_estimate: Some(adjust),
+                ..AddWorklog::new(60)
+            })
+            .unwrap();
+    }
+
+    let requests = server.requests();
+    for (request, &(_, expected)) in requests.iter().zip(&variants) {
+        assert_eq!(request.query("adjustEstimate"), Some(expected.to_owned()));
+        assert_eq!(request.json(), serde_json::json!({ "timeSpentSeconds": 60 }));
+    }
+}
+
+#[test]
+fn add_worklog_with_estimate_changes() {
+    let server = MockServer::start(vec![
+        Response::json(201, WORKLOG),
+        Response::json(201, WORKLOG),
+    ]);
+    let worklogs = jira(&server).issues().worklogs("TEST-1");
+
+    worklogs
+        .add(AddWorklog::new(3600).reduce_by("90m"))
+        .unwrap();
+    worklogs
+        .add(AddWorklog::new(3600).new_estimate("2d 4h"))
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].query("adjustEstimate"),
+        Some("manual".to_owned())
+    );
+    assert_eq!(requests[0].query("reduceBy"), Some("90m".to_owned()));
+    assert_eq!(requests[0].query("newEstimate"), None);
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({ "timeSpentSeconds": 3600 })
+    );
+    assert_eq!(requests[1].query("adjustEstimate"), Some("new".to_owned()));
+    assert_eq!(requests[1].query("newEstimate"), Some("2d 4h".to_owned()));
+    assert_eq!(requests[1].query("reduceBy"), None);
+}